@@ -0,0 +1,229 @@
+//! State machine splitting printed text into characters and ANSI/VT100
+//! escape sequences.
+
+use crate::Attributes;
+
+const MAX_PARAMS: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Ground,
+    Escape,
+    /// After the intermediate bytes of an escape, until its final byte.
+    EscapeIntermediate,
+    Csi,
+}
+
+/// A complete `ESC [ ... final` control sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Csi {
+    params: [u16; MAX_PARAMS],
+    len: usize,
+    /// Set when the parameters started with `?`, `<`, `=` or `>`.
+    pub(crate) private: bool,
+    pub(crate) final_byte: char,
+}
+
+impl Csi {
+    pub(crate) fn params(&self) -> &[u16] {
+        &self.params[..self.len]
+    }
+
+    /// Parameter `index`, or `default` when it is missing or zero.
+    pub(crate) fn param_or(&self, index: usize, default: u16) -> u16 {
+        match self.params().get(index) {
+            Some(0) | None => default,
+            Some(value) => *value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Action {
+    /// A character to print, or a C0 control to carry out.
+    Print(char),
+    Escape(char),
+    Csi(Csi),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AnsiParser {
+    state: State,
+    sequence: Csi,
+    /// Whether a digit or separator has been seen for the current parameter.
+    has_param: bool,
+    /// Set once a parameter past the `MAX_PARAMS`th started; its digits and
+    /// those of any later ones are dropped.
+    overflowed: bool,
+}
+
+impl AnsiParser {
+    pub(crate) const fn new() -> Self {
+        AnsiParser {
+            state: State::Ground,
+            sequence: Csi {
+                params: [0; MAX_PARAMS],
+                len: 0,
+                private: false,
+                final_byte: '\0',
+            },
+            has_param: false,
+            overflowed: false,
+        }
+    }
+
+    /// Feeds one character, returning what to do once something complete was read.
+    pub(crate) fn advance(&mut self, c: char) -> Option<Action> {
+        match self.state {
+            State::Ground => {
+                if c == '\x1b' {
+                    self.state = State::Escape;
+                    None
+                } else {
+                    Some(Action::Print(c))
+                }
+            }
+            State::Escape => match c {
+                '[' => {
+                    self.state = State::Csi;
+                    self.sequence = AnsiParser::new().sequence;
+                    self.has_param = false;
+                    self.overflowed = false;
+                    None
+                }
+                '\x1b' => None,
+                // Intermediate bytes such as the `(` of a charset selection or
+                // the `#` of DECALN. None of those escapes are supported.
+                ' '..='/' => {
+                    self.state = State::EscapeIntermediate;
+                    None
+                }
+                _ => {
+                    self.state = State::Ground;
+                    Some(Action::Escape(c))
+                }
+            },
+            State::EscapeIntermediate => match c {
+                '\x1b' => {
+                    self.state = State::Escape;
+                    None
+                }
+                ' '..='/' => None,
+                _ => {
+                    self.state = State::Ground;
+                    None
+                }
+            },
+            State::Csi => match c {
+                '0'..='9' => {
+                    if !self.has_param {
+                        self.push_param();
+                    }
+                    if self.overflowed {
+                        return None;
+                    }
+                    if let Some(value) = self.sequence.len.checked_sub(1).and_then(|i| self.sequence.params.get_mut(i)) {
+                        *value = value.saturating_mul(10).saturating_add(c as u16 - '0' as u16);
+                    }
+                    None
+                }
+                ';' | ':' => {
+                    if !self.has_param {
+                        self.push_param();
+                    }
+                    self.has_param = false;
+                    None
+                }
+                '?' | '<' | '=' | '>' => {
+                    self.sequence.private = true;
+                    None
+                }
+                ' '..='/' => None,
+                '@'..='~' => {
+                    // A trailing separator leaves one more, empty, parameter.
+                    if self.sequence.len > 0 && !self.has_param {
+                        self.push_param();
+                    }
+                    self.state = State::Ground;
+                    self.sequence.final_byte = c;
+                    Some(Action::Csi(self.sequence))
+                }
+                '\x1b' => {
+                    self.state = State::Escape;
+                    None
+                }
+                // CAN and SUB cancel the sequence; other C0 controls take
+                // effect in the middle of it, as on a VT100.
+                '\x18' | '\x1a' => {
+                    self.state = State::Ground;
+                    None
+                }
+                '\0'..='\x1f' => Some(Action::Print(c)),
+                _ => {
+                    self.state = State::Ground;
+                    None
+                }
+            },
+        }
+    }
+
+    fn push_param(&mut self) {
+        if self.sequence.len < MAX_PARAMS {
+            self.sequence.params[self.sequence.len] = 0;
+            self.sequence.len += 1;
+        } else {
+            self.overflowed = true;
+        }
+        self.has_param = true;
+    }
+}
+
+/// Color `index` of the xterm 256-color palette; the first 16 are the VGA colors.
+pub(crate) fn palette_color(index: u8) -> (u8, u8, u8) {
+    const BASIC: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (170, 0, 0),
+        (0, 170, 0),
+        (170, 85, 0),
+        (0, 0, 170),
+        (170, 0, 170),
+        (0, 170, 170),
+        (170, 170, 170),
+        (85, 85, 85),
+        (255, 85, 85),
+        (85, 255, 85),
+        (255, 255, 85),
+        (85, 85, 255),
+        (255, 85, 255),
+        (85, 255, 255),
+        (255, 255, 255),
+    ];
+    const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    match index {
+        0..=15 => BASIC[index as usize],
+        16..=231 => {
+            let cube = index - 16;
+            (
+                CUBE_LEVELS[(cube / 36) as usize],
+                CUBE_LEVELS[(cube / 6 % 6) as usize],
+                CUBE_LEVELS[(cube % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+/// The attribute an SGR code turns on (1 to 9) or off (22 to 29).
+pub(crate) fn sgr_attribute(code: u16) -> Attributes {
+    match code {
+        1 | 22 => Attributes::BOLD,
+        3 | 23 => Attributes::ITALIC,
+        4 | 24 => Attributes::UNDERLINE,
+        7 | 27 => Attributes::INVERSE,
+        9 | 29 => Attributes::STRIKETHROUGH,
+        _ => Attributes::NONE,
+    }
+}
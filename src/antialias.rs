@@ -0,0 +1,189 @@
+//! Anti-aliased lines, circles and arcs. Partially covered pixels are blended
+//! with what the drawing target already holds, so these read pixels back.
+
+use crate::{CompositeOp, DisplayTextManager, MAX_RADIUS};
+use crate::math::{floor, fract, round, sin_cos, sqrt};
+
+impl DisplayTextManager {
+    /// Draws an anti-aliased line between two sub-pixel positions with Xiaolin
+    /// Wu's algorithm. Pixel centers sit at half-integer coordinates. Nothing
+    /// is drawn unless the endpoints and the distance between them are finite.
+    pub fn draw_line_aa(&mut self, start: (f32, f32), end: (f32, f32), color_rgb: (u8, u8, u8)) {
+        self.without_cursor(|manager| {
+            if !(end.0 - start.0).is_finite() || !(end.1 - start.1).is_finite() {
+                return;
+            }
+            let steep = (end.1 - start.1).abs() > (end.0 - start.0).abs();
+            // Work along the major axis, left to right, in pixel-index coordinates.
+            let (mut start, mut end) = if steep {
+                ((start.1 - 0.5, start.0 - 0.5), (end.1 - 0.5, end.0 - 0.5))
+            } else {
+                ((start.0 - 0.5, start.1 - 0.5), (end.0 - 0.5, end.1 - 0.5))
+            };
+            if start.0 > end.0 {
+                core::mem::swap(&mut start, &mut end);
+            }
+            let delta = (end.0 - start.0, end.1 - start.1);
+            let gradient = if delta.0 == 0.0 { 1.0 } else { delta.1 / delta.0 };
+            let (width, height) = (manager.frame_info.width as f32, manager.frame_info.height as f32);
+            let size = if steep { (height, width) } else { (width, height) };
+
+            let plot = |manager: &mut Self, major: f32, minor: f32, coverage: f32| {
+                let point = if steep { (minor, major) } else { (major, minor) };
+                manager.blend_coverage((point.0 as isize, point.1 as isize), color_rgb, coverage);
+            };
+
+            let first = round(start.0);
+            let first_y = start.1 + gradient * (first - start.0);
+            let gap = 1.0 - fract(start.0 + 0.5);
+            plot(manager, first, floor(first_y), (1.0 - fract(first_y)) * gap);
+            plot(manager, first, floor(first_y) + 1.0, fract(first_y) * gap);
+
+            let last = round(end.0);
+            let last_y = end.1 + gradient * (last - end.0);
+            let gap = fract(end.0 + 0.5);
+            plot(manager, last, floor(last_y), (1.0 - fract(last_y)) * gap);
+            plot(manager, last, floor(last_y) + 1.0, fract(last_y) * gap);
+
+            // Only walk the steps from one pixel before the screen to one past it.
+            let walk_start = (first + 1.0).max(-1.0);
+            let walk_end = (last - 1.0).min(size.0);
+            let mut y = first_y + gradient * (walk_start - first);
+            for x in walk_start as isize..=walk_end as isize {
+                plot(manager, x as f32, floor(y), 1.0 - fract(y));
+                plot(manager, x as f32, floor(y) + 1.0, fract(y));
+                y += gradient;
+            }
+
+            let (left, right) = (first.max(-1.0) as isize, last.min(size.0) as isize);
+            let top = floor(first_y.min(last_y)).max(-1.0) as isize;
+            let bottom = (floor(first_y.max(last_y)) + 1.0).min(size.1) as isize;
+            if left > right || top > bottom {
+                return;
+            }
+            let (position, size) = (
+                (left, top),
+                ((right - left) as usize + 1, (bottom - top) as usize + 1),
+            );
+            if steep {
+                manager.add_clipped_damage((position.1, position.0), (size.1, size.0));
+            } else {
+                manager.add_clipped_damage(position, size);
+            }
+        })
+    }
+
+    /// Draws an anti-aliased circle outline with Wu's circle algorithm.
+    pub fn draw_circle_aa(&mut self, center: (isize, isize), radius: f32, color_rgb: (u8, u8, u8)) {
+        self.draw_arc_aa(center, radius, 0.0, 360.0, color_rgb);
+    }
+
+    /// Draws the part of an anti-aliased circle outline from `start_angle` to
+    /// `end_angle`, in degrees measured clockwise from the positive x axis.
+    /// Nothing is drawn for a radius above `MAX_RADIUS`.
+    pub fn draw_arc_aa(
+        &mut self,
+        center: (isize, isize),
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        color_rgb: (u8, u8, u8),
+    ) {
+        self.without_cursor(|manager| {
+            if !(0.0..=MAX_RADIUS as f32).contains(&radius) || !start_angle.is_finite() || !end_angle.is_finite() {
+                return;
+            }
+            // Once the box around the circle meets the screen, the center is
+            // close enough to it that adding an offset cannot overflow.
+            let reach = radius as usize + 1;
+            let Some(bounds) = manager.clip_rect(
+                (center.0.saturating_sub_unsigned(reach), center.1.saturating_sub_unsigned(reach)),
+                (2 * reach + 1, 2 * reach + 1),
+            ) else {
+                return;
+            };
+            let arc = Arc::new(start_angle, end_angle);
+            let plot = |manager: &mut Self, offset: (isize, isize), coverage: f32| {
+                if arc.contains(offset) {
+                    manager.blend_coverage((center.0 + offset.0, center.1 + offset.1), color_rgb, coverage);
+                }
+            };
+            // Walk one octant and mirror it; `x == 0` and the diagonal are shared
+            // between octants, so those points are only plotted once.
+            let mut x = 0;
+            loop {
+                let exact_y = sqrt(radius * radius - (x * x) as f32);
+                if (x as f32) > exact_y {
+                    break;
+                }
+                let y = floor(exact_y) as isize;
+                let outer = fract(exact_y);
+                for (dy, coverage) in [(y, 1.0 - outer), (y + 1, outer)] {
+                    for (sx, sy) in [(1, 1), (-1, 1), (1, -1), (-1, -1)] {
+                        if (x == 0 && sx < 0) || (dy == 0 && sy < 0) {
+                            continue;
+                        }
+                        plot(manager, (sx * x, sy * dy), coverage);
+                        if x != dy {
+                            plot(manager, (sy * dy, sx * x), coverage);
+                        }
+                    }
+                }
+                x += 1;
+            }
+            manager.add_damage(bounds);
+        })
+    }
+
+    /// Draws `color_rgb` over the pixel at `point` with `coverage` as its alpha,
+    /// from 0 to 1. Points off screen are ignored. Callers add the damage.
+    fn blend_coverage(&mut self, point: (isize, isize), color_rgb: (u8, u8, u8), coverage: f32) {
+        if point.0 < 0
+            || point.1 < 0
+            || point.0 as usize >= self.frame_info.width
+            || point.1 as usize >= self.frame_info.height
+            || coverage <= 0.0
+        {
+            return;
+        }
+        self.blend_pixel_uncheck((point.0 as usize, point.1 as usize), color_rgb, coverage, CompositeOp::SourceOver);
+    }
+}
+
+/// The directions an arc sweeps through, clockwise on screen from `start`.
+#[derive(Debug, Clone, Copy)]
+struct Arc {
+    start: (f32, f32),
+    end: (f32, f32),
+    sweep: f32,
+}
+
+impl Arc {
+    fn new(start_angle: f32, end_angle: f32) -> Self {
+        let sweep = end_angle - start_angle;
+        let sweep = if sweep >= 360.0 { 360.0 } else { sweep - 360.0 * floor(sweep / 360.0) };
+        let (start_sin, start_cos) = sin_cos(start_angle);
+        let (end_sin, end_cos) = sin_cos(end_angle);
+        Arc {
+            start: (start_cos, start_sin),
+            end: (end_cos, end_sin),
+            sweep,
+        }
+    }
+
+    /// Whether the direction of `offset` from the center lies on the arc.
+    fn contains(&self, offset: (isize, isize)) -> bool {
+        if self.sweep >= 360.0 {
+            return true;
+        }
+        let point = (offset.0 as f32, offset.1 as f32);
+        let cross = |a: (f32, f32), b: (f32, f32)| a.0 * b.1 - a.1 * b.0;
+        let after_start = cross(self.start, point) >= 0.0;
+        let before_end = cross(point, self.end) >= 0.0;
+        if self.sweep <= 180.0 {
+            after_start && before_end
+        } else {
+            after_start || before_end
+        }
+    }
+}
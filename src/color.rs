@@ -0,0 +1,204 @@
+//! RGBA colors and Porter-Duff compositing onto the screen.
+//!
+//! The framebuffer has no alpha channel: it counts as fully opaque when
+//! blending, and results that are not opaque are stored as if over black.
+
+use crate::{DisplayTextManager, IndexOutOfRange, Rect};
+
+/// A color with straight (not premultiplied) alpha; 255 is opaque.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Rgba {
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Rgba { r, g, b, a }
+    }
+
+    pub const fn opaque(color_rgb: (u8, u8, u8)) -> Self {
+        Rgba::new(color_rgb.0, color_rgb.1, color_rgb.2, 255)
+    }
+
+    pub const fn rgb(&self) -> (u8, u8, u8) {
+        (self.r, self.g, self.b)
+    }
+}
+
+impl From<(u8, u8, u8)> for Rgba {
+    fn from(color_rgb: (u8, u8, u8)) -> Self {
+        Rgba::opaque(color_rgb)
+    }
+}
+
+impl From<(u8, u8, u8, u8)> for Rgba {
+    fn from(color: (u8, u8, u8, u8)) -> Self {
+        Rgba::new(color.0, color.1, color.2, color.3)
+    }
+}
+
+/// The Porter-Duff operators, combining a source color with the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompositeOp {
+    Clear,
+    Source,
+    Destination,
+    /// The source drawn on top of the screen, the usual way to blend.
+    #[default]
+    SourceOver,
+    DestinationOver,
+    SourceIn,
+    DestinationIn,
+    SourceOut,
+    DestinationOut,
+    SourceAtop,
+    DestinationAtop,
+    Xor,
+}
+
+impl CompositeOp {
+    /// How much of the source and of the destination end up in the result,
+    /// given the alpha of each.
+    fn factors(self, source_alpha: f32, destination_alpha: f32) -> (f32, f32) {
+        match self {
+            CompositeOp::Clear => (0.0, 0.0),
+            CompositeOp::Source => (1.0, 0.0),
+            CompositeOp::Destination => (0.0, 1.0),
+            CompositeOp::SourceOver => (1.0, 1.0 - source_alpha),
+            CompositeOp::DestinationOver => (1.0 - destination_alpha, 1.0),
+            CompositeOp::SourceIn => (destination_alpha, 0.0),
+            CompositeOp::DestinationIn => (0.0, source_alpha),
+            CompositeOp::SourceOut => (1.0 - destination_alpha, 0.0),
+            CompositeOp::DestinationOut => (0.0, 1.0 - source_alpha),
+            CompositeOp::SourceAtop => (destination_alpha, 1.0 - source_alpha),
+            CompositeOp::DestinationAtop => (1.0 - destination_alpha, source_alpha),
+            CompositeOp::Xor => (1.0 - destination_alpha, 1.0 - source_alpha),
+        }
+    }
+}
+
+/// Combines `source`, with its alpha scaled to `source_alpha` (0 to 1), with an
+/// opaque `destination`. In gamma-correct mode the channels are mixed as linear
+/// light rather than as sRGB-encoded values.
+pub(crate) fn composite(
+    source: (u8, u8, u8),
+    source_alpha: f32,
+    destination: (u8, u8, u8),
+    op: CompositeOp,
+    gamma_correct: bool,
+) -> (u8, u8, u8) {
+    let source_alpha = source_alpha.clamp(0.0, 1.0);
+    let (source_factor, destination_factor) = op.factors(source_alpha, 1.0);
+    let mix = |source: u8, destination: u8| {
+        if gamma_correct {
+            let linear = source_factor * source_alpha * to_linear(source) + destination_factor * to_linear(destination);
+            from_linear(linear)
+        } else {
+            let value = source_factor * source_alpha * source as f32 + destination_factor * destination as f32;
+            (value + 0.5).min(255.0) as u8
+        }
+    };
+    (
+        mix(source.0, destination.0),
+        mix(source.1, destination.1),
+        mix(source.2, destination.2),
+    )
+}
+
+fn to_linear(value: u8) -> f32 {
+    SRGB_TO_LINEAR[value as usize] as f32
+}
+
+/// The sRGB value whose linear intensity is closest to `linear`.
+fn from_linear(linear: f32) -> u8 {
+    let linear = (linear + 0.5).clamp(0.0, 65535.0) as u16;
+    let above = SRGB_TO_LINEAR.partition_point(|value| *value < linear).min(255);
+    if above > 0 && linear - SRGB_TO_LINEAR[above - 1] < SRGB_TO_LINEAR[above].saturating_sub(linear) {
+        above as u8 - 1
+    } else {
+        above as u8
+    }
+}
+
+/// Linear intensity of each sRGB-encoded channel value, scaled to `u16`.
+#[rustfmt::skip]
+const SRGB_TO_LINEAR: [u16; 256] = [
+    0, 20, 40, 60, 80, 99, 119, 139, 159, 179, 199, 219,
+    241, 264, 288, 313, 340, 367, 396, 427, 458, 491, 526, 562,
+    599, 637, 677, 718, 761, 805, 851, 898, 947, 997, 1048, 1101,
+    1156, 1212, 1270, 1330, 1391, 1453, 1517, 1583, 1651, 1720, 1790, 1863,
+    1937, 2013, 2090, 2170, 2250, 2333, 2418, 2504, 2592, 2681, 2773, 2866,
+    2961, 3058, 3157, 3258, 3360, 3464, 3570, 3678, 3788, 3900, 4014, 4129,
+    4247, 4366, 4488, 4611, 4736, 4864, 4993, 5124, 5257, 5392, 5530, 5669,
+    5810, 5953, 6099, 6246, 6395, 6547, 6700, 6856, 7014, 7174, 7335, 7500,
+    7666, 7834, 8004, 8177, 8352, 8528, 8708, 8889, 9072, 9258, 9445, 9635,
+    9828, 10022, 10219, 10417, 10619, 10822, 11028, 11235, 11446, 11658, 11873, 12090,
+    12309, 12530, 12754, 12980, 13209, 13440, 13673, 13909, 14146, 14387, 14629, 14874,
+    15122, 15371, 15623, 15878, 16135, 16394, 16656, 16920, 17187, 17456, 17727, 18001,
+    18277, 18556, 18837, 19121, 19407, 19696, 19987, 20281, 20577, 20876, 21177, 21481,
+    21787, 22096, 22407, 22721, 23038, 23357, 23678, 24002, 24329, 24658, 24990, 25325,
+    25662, 26001, 26344, 26688, 27036, 27386, 27739, 28094, 28452, 28813, 29176, 29542,
+    29911, 30282, 30656, 31033, 31412, 31794, 32179, 32567, 32957, 33350, 33745, 34143,
+    34544, 34948, 35355, 35764, 36176, 36591, 37008, 37429, 37852, 38278, 38706, 39138,
+    39572, 40009, 40449, 40891, 41337, 41785, 42236, 42690, 43147, 43606, 44069, 44534,
+    45002, 45473, 45947, 46423, 46903, 47385, 47871, 48359, 48850, 49344, 49841, 50341,
+    50844, 51349, 51858, 52369, 52884, 53401, 53921, 54445, 54971, 55500, 56032, 56567,
+    57105, 57646, 58190, 58737, 59287, 59840, 60396, 60955, 61517, 62082, 62650, 63221,
+    63795, 64372, 64952, 65535,
+];
+
+impl DisplayTextManager {
+    /// Selects whether blending mixes colors as linear light, which keeps
+    /// translucent and anti-aliased edges from looking too dark. Off by default.
+    pub fn set_gamma_correct(&mut self, gamma_correct: bool) {
+        self.gamma_correct = gamma_correct;
+    }
+
+    /// Composites `color` onto the pixel at `position` with the operator `op`.
+    pub fn blend_pixel(
+        &mut self,
+        position: (usize, usize),
+        color: Rgba,
+        op: CompositeOp,
+    ) -> Result<(), IndexOutOfRange> {
+        self.without_cursor(|manager| {
+            if position.0 >= manager.frame_info.width || position.1 >= manager.frame_info.height {
+                return Err(IndexOutOfRange);
+            }
+            manager.blend_pixel_uncheck(position, color.rgb(), color.a as f32 / 255.0, op);
+            manager.add_damage(Rect::new(position, (1, 1)));
+            Ok(())
+        })
+    }
+
+    /// Composites `color` onto every pixel of a rectangle, clipped to the screen.
+    pub fn blend_rect(&mut self, position: (isize, isize), size: (usize, usize), color: Rgba, op: CompositeOp) {
+        self.without_cursor(|manager| {
+            let Some(rect) = manager.clip_rect(position, size) else {
+                return;
+            };
+            let alpha = color.a as f32 / 255.0;
+            for y in rect.y..rect.y + rect.height {
+                for x in rect.x..rect.x + rect.width {
+                    manager.blend_pixel_uncheck((x, y), color.rgb(), alpha, op);
+                }
+            }
+            manager.add_damage(rect);
+        })
+    }
+
+    /// Callers add the damage.
+    pub(crate) fn blend_pixel_uncheck(
+        &mut self,
+        position: (usize, usize),
+        color_rgb: (u8, u8, u8),
+        alpha: f32,
+        op: CompositeOp,
+    ) {
+        let blended = composite(color_rgb, alpha, self.get_pixel_uncheck(position), op, self.gamma_correct);
+        self.set_pixel_uncheck(position, blended);
+    }
+}
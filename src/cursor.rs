@@ -0,0 +1,193 @@
+//! The visible text cursor. It is drawn by inverting the pixels it covers, so
+//! it shows over any cell and drawing it again removes it. Drawing operations
+//! remove it while they run, and reading pixels back looks through it.
+
+use crate::{DisplayTextManager, Rect};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CursorStyle {
+    /// Covers the whole cell.
+    #[default]
+    Block,
+    /// A line along the bottom of the cell.
+    Underline,
+    /// A vertical line along the left edge of the cell.
+    Bar,
+}
+
+/// How the cursor looks, and where it is drawn on screen.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CursorState {
+    style: CursorStyle,
+    pub(crate) visible: bool,
+    /// Ticks between the cursor turning on and off, if it blinks.
+    blink_period: Option<u32>,
+    ticks: u32,
+    /// Whether a blinking cursor is in the shown part of its period.
+    blink_on: bool,
+    /// The cell the pixels are currently inverted at.
+    pub(crate) drawn_at: Option<(usize, usize)>,
+    /// The pixels inverted in the framebuffer as of the last `flush`, when
+    /// drawing goes to a back buffer.
+    pub(crate) flushed: Option<Rect>,
+}
+
+impl CursorState {
+    pub(crate) const fn new() -> Self {
+        CursorState {
+            style: CursorStyle::Block,
+            visible: false,
+            blink_period: None,
+            ticks: 0,
+            blink_on: true,
+            drawn_at: None,
+            flushed: None,
+        }
+    }
+
+    /// Starts a new blink period with the cursor shown, so it stays put
+    /// while text is being printed.
+    pub(crate) fn restart_blink(&mut self) {
+        self.ticks = 0;
+        self.blink_on = true;
+    }
+}
+
+impl DisplayTextManager {
+    /// Shows or hides the cursor. It starts out hidden.
+    pub fn set_cursor_visible(&mut self, visible: bool) {
+        self.erase_cursor();
+        self.cursor_state.visible = visible;
+        self.cursor_state.restart_blink();
+        self.draw_cursor();
+    }
+
+    pub fn cursor_visible(&self) -> bool {
+        self.cursor_state.visible
+    }
+
+    pub fn set_cursor_style(&mut self, style: CursorStyle) {
+        self.erase_cursor();
+        self.cursor_state.style = style;
+        self.draw_cursor();
+    }
+
+    pub fn cursor_style(&self) -> CursorStyle {
+        self.cursor_state.style
+    }
+
+    /// Makes the cursor blink, turning on and off every `period` calls to
+    /// `tick`, or keeps it steady with `None`.
+    pub fn set_cursor_blink(&mut self, period: Option<u32>) {
+        self.cursor_state.blink_period = period.filter(|period| *period > 0);
+        self.cursor_state.restart_blink();
+        self.draw_cursor();
+    }
+
+    /// Advances the blinking of the cursor by one step. From a timer interrupt,
+    /// call the free function `tick` instead: locking `DISPLAY_TEXT` there
+    /// deadlocks when the interrupt arrives while it is locked.
+    pub fn tick(&mut self) {
+        let Some(period) = self.cursor_state.blink_period else {
+            return;
+        };
+        self.cursor_state.ticks += 1;
+        if self.cursor_state.ticks >= period {
+            self.cursor_state.ticks = 0;
+            self.cursor_state.blink_on = !self.cursor_state.blink_on;
+            if self.cursor_state.blink_on {
+                self.draw_cursor();
+            } else {
+                self.erase_cursor();
+            }
+        }
+    }
+
+    /// Draws the cursor at `cursor` if it should be seen: it is visible, not
+    /// blinked off, and the view shows the live text.
+    pub(crate) fn draw_cursor(&mut self) {
+        let state = self.cursor_state;
+        let (columns, rows) = self.text_size();
+        let shown = state.visible
+            && state.blink_on
+            && self.view_offset == 0
+            && self.cursor.0 < columns
+            && self.cursor.1 < rows;
+        let wanted = if shown { Some(self.cursor) } else { None };
+        if state.drawn_at == wanted {
+            return;
+        }
+        self.erase_cursor();
+        if shown {
+            self.invert_rect(self.cursor_rect(self.cursor));
+            self.cursor_state.drawn_at = Some(self.cursor);
+        }
+    }
+
+    /// Restores the pixels under the cursor, if it is drawn.
+    pub(crate) fn erase_cursor(&mut self) {
+        if let Some(position) = self.cursor_state.drawn_at.take() {
+            self.invert_rect(self.cursor_rect(position));
+        }
+    }
+
+    /// Runs `draw` with the cursor erased, so it neither reads nor overwrites
+    /// the inverted pixels, and draws the cursor again afterwards.
+    pub(crate) fn without_cursor<T>(&mut self, draw: impl FnOnce(&mut Self) -> T) -> T {
+        if self.cursor_state.drawn_at.is_none() {
+            return draw(self);
+        }
+        self.erase_cursor();
+        let result = draw(self);
+        self.draw_cursor();
+        result
+    }
+
+    /// Whether the pixel at `position` is inverted by the cursor, in the
+    /// drawing target or, with `on_screen`, in the framebuffer.
+    pub(crate) fn cursor_covers(&self, position: (usize, usize), on_screen: bool) -> bool {
+        let rect = if on_screen && !self.back_buffer.is_null() {
+            self.cursor_state.flushed
+        } else {
+            self.cursor_state.drawn_at.map(|drawn_at| self.cursor_rect(drawn_at))
+        };
+        rect.is_some_and(|rect| {
+            (rect.x..rect.x + rect.width).contains(&position.0) && (rect.y..rect.y + rect.height).contains(&position.1)
+        })
+    }
+
+    /// Remembers what `flush` put on screen.
+    pub(crate) fn cursor_flushed(&mut self) {
+        self.cursor_state.flushed = self.cursor_state.drawn_at.map(|drawn_at| self.cursor_rect(drawn_at));
+    }
+
+    /// The pixels the cursor covers at `(column, row)`.
+    fn cursor_rect(&self, position: (usize, usize)) -> Rect {
+        let (cell_width, cell_height) = self.font.cell_size();
+        let origin = (position.0 * cell_width, position.1 * cell_height);
+        match self.cursor_state.style {
+            CursorStyle::Block => Rect::new(origin, (cell_width, cell_height)),
+            CursorStyle::Underline => {
+                let thickness = (cell_height / 8).max(1);
+                Rect::new((origin.0, origin.1 + cell_height - thickness), (cell_width, thickness))
+            }
+            CursorStyle::Bar => Rect::new(origin, ((cell_width / 8).max(1), cell_height)),
+        }
+    }
+
+    /// Replaces every pixel in `rect`, which must be on screen, with its
+    /// complementary color.
+    fn invert_rect(&mut self, rect: Rect) {
+        for y in rect.y..rect.y + rect.height {
+            for x in rect.x..rect.x + rect.width {
+                let color = self.get_pixel_uncheck((x, y));
+                self.set_pixel_uncheck((x, y), inverted(color));
+            }
+        }
+        self.add_damage(rect);
+    }
+}
+
+pub(crate) fn inverted((r, g, b): (u8, u8, u8)) -> (u8, u8, u8) {
+    (255 - r, 255 - g, 255 - b)
+}
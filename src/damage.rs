@@ -0,0 +1,66 @@
+//! Bookkeeping of the screen areas changed since the last present.
+
+use crate::Rect;
+
+const MAX_RECTS: usize = 16;
+
+/// A small set of disjoint-ish rectangles. Overlapping rectangles, and
+/// neighbours whose union wastes no area, are merged as they are added; when
+/// the list is full the new one is merged into whichever rectangle grows least.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DamageList {
+    rects: [Rect; MAX_RECTS],
+    len: usize,
+}
+
+impl DamageList {
+    pub(crate) const fn new() -> Self {
+        DamageList {
+            rects: [Rect::new((0, 0), (0, 0)); MAX_RECTS],
+            len: 0,
+        }
+    }
+
+    pub(crate) fn rects(&self) -> &[Rect] {
+        &self.rects[..self.len]
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    pub(crate) fn add(&mut self, rect: Rect) {
+        if rect.is_empty() {
+            return;
+        }
+        let mut rect = rect;
+        loop {
+            let mergeable = self.rects().iter().position(|other| {
+                other.intersects(&rect) || other.union(&rect).area() <= other.area() + rect.area()
+            });
+            if let Some(index) = mergeable {
+                rect = rect.union(&self.remove(index));
+                continue;
+            }
+            if self.len < MAX_RECTS {
+                break;
+            }
+            let cheapest = (0..self.len)
+                .min_by_key(|index| {
+                    let other = self.rects[*index];
+                    other.union(&rect).area() - other.area()
+                })
+                .unwrap_or(0);
+            rect = rect.union(&self.remove(cheapest));
+        }
+        self.rects[self.len] = rect;
+        self.len += 1;
+    }
+
+    fn remove(&mut self, index: usize) -> Rect {
+        let rect = self.rects[index];
+        self.len -= 1;
+        self.rects[index] = self.rects[self.len];
+        rect
+    }
+}
@@ -0,0 +1,292 @@
+//! 2D drawing primitives. Shapes take signed coordinates and are clipped to
+//! the screen, so they may lie partly or entirely outside of it.
+
+use crate::{DisplayTextManager, Rect};
+
+/// Largest radius circles and ellipses are drawn with, which keeps the
+/// midpoint algorithms in 64-bit integers.
+pub const MAX_RADIUS: usize = 1 << 14;
+
+impl DisplayTextManager {
+    /// Draws `length` pixels rightwards from `position`.
+    pub fn draw_hline(&mut self, position: (isize, isize), length: usize, color_rgb: (u8, u8, u8)) {
+        self.without_cursor(|manager| {
+            let pixel = manager.pixel_encoder.encode(color_rgb);
+            let end = position.0.saturating_add_unsigned(length);
+            if let Some(span) = manager.clip_span(position.1, position.0, end) {
+                manager.write_span_uncheck((span.x, span.y), span.width, pixel);
+                manager.add_damage(span);
+            }
+        })
+    }
+
+    /// Draws `length` pixels downwards from `position`.
+    pub fn draw_vline(&mut self, position: (isize, isize), length: usize, color_rgb: (u8, u8, u8)) {
+        self.fill_rect(position, (1, length), color_rgb);
+    }
+
+    /// Draws a line from `start` to `end`, both included, with the pixels
+    /// Bresenham's algorithm picks. Only the steps that land on screen are
+    /// walked, so lines may be as long as the coordinates allow.
+    pub fn draw_line(&mut self, start: (isize, isize), end: (isize, isize), color_rgb: (u8, u8, u8)) {
+        self.without_cursor(|manager| {
+            if start.1 == end.1 {
+                let x = start.0.min(end.0);
+                manager.draw_hline((x, start.1), start.0.abs_diff(end.0).saturating_add(1), color_rgb);
+                return;
+            }
+            if start.0 == end.0 {
+                let y = start.1.min(end.1);
+                manager.draw_vline((start.0, y), start.1.abs_diff(end.1).saturating_add(1), color_rgb);
+                return;
+            }
+            let pixel = manager.pixel_encoder.encode(color_rgb);
+            let (start, end) = ([start.0 as i128, start.1 as i128], [end.0 as i128, end.1 as i128]);
+            let size = [manager.frame_info.width as i128, manager.frame_info.height as i128];
+            let delta = [start[0].abs_diff(end[0]), start[1].abs_diff(end[1])];
+            let step = [(end[0] - start[0]).signum(), (end[1] - start[1]).signum()];
+            // One pixel per step along the axis the line is longer on. At step `k`
+            // the other coordinate is `k * delta[minor] / delta[major]` rounded to
+            // the nearest integer, away from `start` on ties, like Bresenham's.
+            let (major, minor) = if delta[0] >= delta[1] { (0, 1) } else { (1, 0) };
+            let (first, last) = if step[major] > 0 {
+                (-start[major], size[major] - 1 - start[major])
+            } else {
+                (start[major] - size[major] + 1, start[major])
+            };
+            let mut drawn: Option<((usize, usize), (usize, usize))> = None;
+            for k in first.max(0)..=last.min(delta[major] as i128) {
+                let product = k as u128 * delta[minor];
+                let mut offset = product / delta[major];
+                if 2 * (product % delta[major]) >= delta[major] {
+                    offset += 1;
+                }
+                let mut point = [0; 2];
+                point[major] = start[major] + step[major] * k;
+                point[minor] = start[minor] + step[minor] * offset as i128;
+                if (0..size[minor]).contains(&point[minor]) {
+                    let point = (point[0] as usize, point[1] as usize);
+                    manager.write_span_uncheck(point, 1, pixel);
+                    drawn = Some((drawn.map_or(point, |drawn| drawn.0), point));
+                }
+            }
+            if let Some((first, last)) = drawn {
+                let top_left = (first.0.min(last.0), first.1.min(last.1));
+                manager.add_damage(Rect::new(
+                    top_left,
+                    (first.0.abs_diff(last.0) + 1, first.1.abs_diff(last.1) + 1),
+                ));
+            }
+        })
+    }
+
+    /// Draws the outline of a rectangle, one pixel wide.
+    pub fn draw_rect(&mut self, position: (isize, isize), size: (usize, usize), color_rgb: (u8, u8, u8)) {
+        if size.0 == 0 || size.1 == 0 {
+            return;
+        }
+        let right = position.0.saturating_add_unsigned(size.0 - 1);
+        let bottom = position.1.saturating_add_unsigned(size.1 - 1);
+        self.draw_hline(position, size.0, color_rgb);
+        self.draw_hline((position.0, bottom), size.0, color_rgb);
+        self.draw_vline(position, size.1, color_rgb);
+        self.draw_vline((right, position.1), size.1, color_rgb);
+    }
+
+    pub fn fill_rect(&mut self, position: (isize, isize), size: (usize, usize), color_rgb: (u8, u8, u8)) {
+        self.without_cursor(|manager| {
+            if let Some(rect) = manager.clip_rect(position, size) {
+                manager.fill_rect_uncheck((rect.x, rect.y), (rect.width, rect.height), color_rgb);
+            }
+        })
+    }
+
+    /// Draws the outline of a circle with the midpoint circle algorithm.
+    /// Nothing is drawn for a radius above `MAX_RADIUS`.
+    pub fn draw_circle(&mut self, center: (isize, isize), radius: usize, color_rgb: (u8, u8, u8)) {
+        self.without_cursor(|manager| {
+            let Some(bounds) = manager.ellipse_bounds(center, (radius, radius)) else {
+                return;
+            };
+            let pixel = manager.pixel_encoder.encode(color_rgb);
+            for_each_circle_point(radius, |x, y| {
+                for (dx, dy) in [(x, y), (y, x)] {
+                    manager.plot((center.0 + dx, center.1 + dy), pixel);
+                    manager.plot((center.0 - dx, center.1 + dy), pixel);
+                    manager.plot((center.0 + dx, center.1 - dy), pixel);
+                    manager.plot((center.0 - dx, center.1 - dy), pixel);
+                }
+            });
+            manager.add_damage(bounds);
+        })
+    }
+
+    /// Nothing is drawn for a radius above `MAX_RADIUS`.
+    pub fn fill_circle(&mut self, center: (isize, isize), radius: usize, color_rgb: (u8, u8, u8)) {
+        self.without_cursor(|manager| {
+            let Some(bounds) = manager.ellipse_bounds(center, (radius, radius)) else {
+                return;
+            };
+            let pixel = manager.pixel_encoder.encode(color_rgb);
+            for_each_circle_point(radius, |x, y| {
+                for (dx, dy) in [(x, y), (y, x)] {
+                    manager.span(center.1 + dy, center.0 - dx, center.0 + dx, pixel);
+                    manager.span(center.1 - dy, center.0 - dx, center.0 + dx, pixel);
+                }
+            });
+            manager.add_damage(bounds);
+        })
+    }
+
+    /// Draws the outline of an axis-aligned ellipse with the midpoint ellipse
+    /// algorithm. Nothing is drawn if a radius is above `MAX_RADIUS`.
+    pub fn draw_ellipse(&mut self, center: (isize, isize), radii: (usize, usize), color_rgb: (u8, u8, u8)) {
+        self.without_cursor(|manager| {
+            let Some(bounds) = manager.ellipse_bounds(center, radii) else {
+                return;
+            };
+            let pixel = manager.pixel_encoder.encode(color_rgb);
+            for_each_ellipse_point(radii, |x, y| {
+                manager.plot((center.0 + x, center.1 + y), pixel);
+                manager.plot((center.0 - x, center.1 + y), pixel);
+                manager.plot((center.0 + x, center.1 - y), pixel);
+                manager.plot((center.0 - x, center.1 - y), pixel);
+            });
+            manager.add_damage(bounds);
+        })
+    }
+
+    /// Nothing is drawn if a radius is above `MAX_RADIUS`.
+    pub fn fill_ellipse(&mut self, center: (isize, isize), radii: (usize, usize), color_rgb: (u8, u8, u8)) {
+        self.without_cursor(|manager| {
+            let Some(bounds) = manager.ellipse_bounds(center, radii) else {
+                return;
+            };
+            let pixel = manager.pixel_encoder.encode(color_rgb);
+            for_each_ellipse_point(radii, |x, y| {
+                manager.span(center.1 + y, center.0 - x, center.0 + x, pixel);
+                manager.span(center.1 - y, center.0 - x, center.0 + x, pixel);
+            });
+            manager.add_damage(bounds);
+        })
+    }
+
+    /// The part of a rectangle that lies on screen, if any.
+    pub(crate) fn clip_rect(&self, position: (isize, isize), size: (usize, usize)) -> Option<Rect> {
+        let left = position.0.max(0);
+        let top = position.1.max(0);
+        let right = position.0.saturating_add_unsigned(size.0).min(self.frame_info.width as isize);
+        let bottom = position.1.saturating_add_unsigned(size.1).min(self.frame_info.height as isize);
+        if left >= right || top >= bottom {
+            return None;
+        }
+        Some(Rect::new(
+            (left as usize, top as usize),
+            ((right - left) as usize, (bottom - top) as usize),
+        ))
+    }
+
+    /// The on-screen part of row `y` from `start` up to (excluding) `end`.
+    fn clip_span(&self, y: isize, start: isize, end: isize) -> Option<Rect> {
+        let length = if start < end { start.abs_diff(end) } else { 0 };
+        self.clip_rect((start, y), (length, 1))
+    }
+
+    /// Writes an encoded pixel if `point` is on screen. Callers add the damage.
+    pub(crate) fn plot(&mut self, point: (isize, isize), pixel: u32) {
+        if point.0 >= 0
+            && point.1 >= 0
+            && (point.0 as usize) < self.frame_info.width
+            && (point.1 as usize) < self.frame_info.height
+        {
+            self.write_span_uncheck((point.0 as usize, point.1 as usize), 1, pixel);
+        }
+    }
+
+    /// Writes an encoded pixel from `start` to `end`, both included, on row `y`,
+    /// clipped to the screen. Callers add the damage.
+    pub(crate) fn span(&mut self, y: isize, start: isize, end: isize, pixel: u32) {
+        if let Some(span) = self.clip_span(y, start, end.saturating_add(1)) {
+            self.write_span_uncheck((span.x, span.y), span.width, pixel);
+        }
+    }
+
+    pub(crate) fn add_clipped_damage(&mut self, position: (isize, isize), size: (usize, usize)) {
+        if let Some(rect) = self.clip_rect(position, size) {
+            self.add_damage(rect);
+        }
+    }
+
+    /// The on-screen part of the bounding box of an ellipse, or `None` if
+    /// there is nothing to draw. Once this returns a box, `center` plus or
+    /// minus a radius cannot overflow.
+    fn ellipse_bounds(&self, center: (isize, isize), radii: (usize, usize)) -> Option<Rect> {
+        if radii.0 > MAX_RADIUS || radii.1 > MAX_RADIUS {
+            return None;
+        }
+        self.clip_rect(
+            (center.0.saturating_sub_unsigned(radii.0), center.1.saturating_sub_unsigned(radii.1)),
+            (2 * radii.0 + 1, 2 * radii.1 + 1),
+        )
+    }
+}
+
+/// Calls `plot` with the points of the first octant of a circle centered on
+/// the origin, as found by the midpoint circle algorithm.
+fn for_each_circle_point(radius: usize, mut plot: impl FnMut(isize, isize)) {
+    let radius = radius as isize;
+    let (mut x, mut y, mut error) = (radius, 0, 1 - radius);
+    while x >= y {
+        plot(x, y);
+        y += 1;
+        if error < 0 {
+            error += 2 * y + 1;
+        } else {
+            x -= 1;
+            error += 2 * (y - x) + 1;
+        }
+    }
+}
+
+/// Calls `plot` with the points of the first quadrant of an ellipse centered on
+/// the origin, as found by the midpoint ellipse algorithm. Decision variables
+/// are scaled by 4 to stay in integers.
+fn for_each_ellipse_point(radii: (usize, usize), mut plot: impl FnMut(isize, isize)) {
+    let (a, b) = (radii.0 as i64, radii.1 as i64);
+    if b == 0 {
+        for x in 0..=a {
+            plot(x as isize, 0);
+        }
+        return;
+    }
+    let (a2, b2) = (a * a, b * b);
+    let (mut x, mut y) = (0i64, b);
+    let mut dx = 0;
+    let mut dy = 2 * a2 * y;
+    let mut decision = 4 * b2 - 4 * a2 * b + a2;
+    while dx < dy {
+        plot(x as isize, y as isize);
+        x += 1;
+        dx += 2 * b2;
+        if decision < 0 {
+            decision += 4 * (dx + b2);
+        } else {
+            y -= 1;
+            dy -= 2 * a2;
+            decision += 4 * (dx - dy + b2);
+        }
+    }
+    let mut decision = b2 * (2 * x + 1) * (2 * x + 1) + 4 * a2 * (y - 1) * (y - 1) - 4 * a2 * b2;
+    while y >= 0 {
+        plot(x as isize, y as isize);
+        y -= 1;
+        dy -= 2 * a2;
+        if decision > 0 {
+            decision += 4 * (a2 - dy);
+        } else {
+            x += 1;
+            dx += 2 * b2;
+            decision += 4 * (dx - dy + a2);
+        }
+    }
+}
@@ -0,0 +1,374 @@
+//! Fonts used to draw text: the built-in 8x8 font and PC Screen Fonts
+//! (PSF1/PSF2) such as the Linux console fonts.
+
+mod builtin;
+#[cfg(feature = "cp437")]
+mod cp437;
+#[cfg(any(feature = "latin1", feature = "cp437"))]
+mod latin1;
+
+const PSF1_MAGIC: [u8; 2] = [0x36, 0x04];
+const PSF1_MODE_512: u8 = 0x01;
+const PSF1_MODE_HAS_TABLE: u8 = 0x02;
+const PSF1_MODE_HAS_SEQUENCES: u8 = 0x04;
+const PSF2_MAGIC: [u8; 4] = [0x72, 0xb5, 0x4a, 0x86];
+const PSF2_HAS_UNICODE_TABLE: u32 = 0x01;
+
+const NO_GLYPH: u16 = u16::MAX;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontError {
+    /// The data starts with neither the PSF1 nor the PSF2 magic number.
+    InvalidMagic,
+    /// The header announces more data than the blob contains.
+    Truncated,
+    /// The header is well formed but describes an empty or unusable font.
+    Unsupported,
+}
+
+// There is no allocator to box `PsfFont` into, and a font is only stored once.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Copy)]
+pub enum Font {
+    /// The 8x8 font compiled into the crate, with 2 pixels of spacing.
+    Builtin,
+    Psf(PsfFont),
+}
+
+impl Font {
+    /// Parses a PSF1 or PSF2 font, for example one pulled in with `include_bytes!`.
+    pub fn from_psf(data: &'static [u8]) -> Result<Font, FontError> {
+        PsfFont::parse(data).map(Font::Psf)
+    }
+
+    /// Size in pixels of the cell each character occupies on screen.
+    pub fn cell_size(&self) -> (usize, usize) {
+        match self {
+            Font::Builtin => (10, 10),
+            Font::Psf(font) => (font.width, font.height),
+        }
+    }
+
+    /// Returns the glyph for `c`, or `None` if the font has no glyph for it.
+    pub fn glyph(&self, c: char) -> Option<Glyph> {
+        match self {
+            Font::Builtin => builtin_glyph(c).map(|pixels| Glyph::Builtin {
+                pixels,
+                joins_neighbours: ('\u{2500}'..='\u{259F}').contains(&c),
+            }),
+            Font::Psf(font) => font.glyph_index(c).map(|index| font.glyph(index)),
+        }
+    }
+
+    /// The glyph drawn for characters the font does not cover.
+    pub fn fallback_glyph(&self) -> Glyph {
+        match self {
+            Font::Builtin => Glyph::Builtin {
+                pixels: builtin::FALLBACK,
+                joins_neighbours: false,
+            },
+            Font::Psf(font) => {
+                let index = font
+                    .glyph_index('\u{FFFD}')
+                    .or_else(|| font.glyph_index('?'))
+                    .unwrap_or(0);
+                font.glyph(index)
+            }
+        }
+    }
+}
+
+/// Looks `c` up in the built-in ASCII glyphs, then in the optional sets.
+fn builtin_glyph(c: char) -> Option<[[bool; 8]; 8]> {
+    if let Some(pixels) = builtin::glyph(c) {
+        return Some(pixels);
+    }
+    #[cfg(feature = "latin1")]
+    if let Some(pixels) = latin1::glyph(c) {
+        return Some(pixels);
+    }
+    #[cfg(feature = "cp437")]
+    if let Some(pixels) = cp437::glyph(c) {
+        return Some(pixels);
+    }
+    None
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Glyph {
+    Builtin {
+        pixels: [[bool; 8]; 8],
+        /// Box-drawing and block glyphs repeat their last row and column into
+        /// the cell spacing, so lines join up with the neighbouring cells.
+        joins_neighbours: bool,
+    },
+    Bitmap {
+        data: &'static [u8],
+        width: usize,
+        height: usize,
+    },
+}
+
+impl Glyph {
+    pub fn size(&self) -> (usize, usize) {
+        match self {
+            Glyph::Builtin { .. } => (8, 8),
+            Glyph::Bitmap { width, height, .. } => (*width, *height),
+        }
+    }
+
+    /// Whether the pixel at `(x, y)` is set. Pixels outside the glyph are unset,
+    /// except for built-in glyphs that join their neighbours.
+    pub fn pixel(&self, x: usize, y: usize) -> bool {
+        match self {
+            Glyph::Builtin {
+                pixels,
+                joins_neighbours,
+            } => {
+                if *joins_neighbours {
+                    pixels[y.min(7)][x.min(7)]
+                } else {
+                    x < 8 && y < 8 && pixels[y][x]
+                }
+            }
+            Glyph::Bitmap {
+                data,
+                width,
+                height,
+            } => {
+                if x >= *width || y >= *height {
+                    return false;
+                }
+                let row_len = width.div_ceil(8);
+                data[y * row_len + x / 8] & (0x80 >> (x % 8)) != 0
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UnicodeTable {
+    Psf1(&'static [u8]),
+    Psf2(&'static [u8]),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PsfFont {
+    glyphs: &'static [u8],
+    glyph_count: usize,
+    bytes_per_glyph: usize,
+    width: usize,
+    height: usize,
+    unicode_table: Option<UnicodeTable>,
+    /// Glyph indices of U+0000 to U+00FF, so the common case skips the table scan.
+    low_glyphs: [u16; 256],
+}
+
+impl PsfFont {
+    pub fn parse(data: &'static [u8]) -> Result<PsfFont, FontError> {
+        if data.starts_with(&PSF1_MAGIC) {
+            Self::parse_psf1(data)
+        } else if data.starts_with(&PSF2_MAGIC) {
+            Self::parse_psf2(data)
+        } else {
+            Err(FontError::InvalidMagic)
+        }
+    }
+
+    fn parse_psf1(data: &'static [u8]) -> Result<PsfFont, FontError> {
+        let (mode, height) = match data.get(2..4) {
+            Some(header) => (header[0], header[1] as usize),
+            None => return Err(FontError::Truncated),
+        };
+        let glyph_count = if mode & PSF1_MODE_512 != 0 { 512 } else { 256 };
+        let glyphs_end = 4 + glyph_count * height;
+        let unicode_table = if mode & (PSF1_MODE_HAS_TABLE | PSF1_MODE_HAS_SEQUENCES) != 0 {
+            Some(UnicodeTable::Psf1(data.get(glyphs_end..).ok_or(FontError::Truncated)?))
+        } else {
+            None
+        };
+        Self::new(data, 4, glyph_count, height, 8, height, unicode_table)
+    }
+
+    fn parse_psf2(data: &'static [u8]) -> Result<PsfFont, FontError> {
+        let field = |index: usize| {
+            data.get(index * 4..index * 4 + 4)
+                .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+                .ok_or(FontError::Truncated)
+        };
+        let header_size = field(2)?;
+        let flags = field(3)? as u32;
+        let glyph_count = field(4)?;
+        let bytes_per_glyph = field(5)?;
+        let height = field(6)?;
+        let width = field(7)?;
+        let glyph_bytes = width.div_ceil(8).checked_mul(height).ok_or(FontError::Unsupported)?;
+        if header_size < 32 || bytes_per_glyph < glyph_bytes {
+            return Err(FontError::Unsupported);
+        }
+        let glyphs_end = glyph_count
+            .checked_mul(bytes_per_glyph)
+            .and_then(|len| len.checked_add(header_size))
+            .ok_or(FontError::Truncated)?;
+        let unicode_table = if flags & PSF2_HAS_UNICODE_TABLE != 0 {
+            Some(UnicodeTable::Psf2(data.get(glyphs_end..).ok_or(FontError::Truncated)?))
+        } else {
+            None
+        };
+        Self::new(data, header_size, glyph_count, bytes_per_glyph, width, height, unicode_table)
+    }
+
+    fn new(
+        data: &'static [u8],
+        header_size: usize,
+        glyph_count: usize,
+        bytes_per_glyph: usize,
+        width: usize,
+        height: usize,
+        unicode_table: Option<UnicodeTable>,
+    ) -> Result<PsfFont, FontError> {
+        if glyph_count == 0 || width == 0 || height == 0 {
+            return Err(FontError::Unsupported);
+        }
+        let glyphs = data
+            .get(header_size..header_size + glyph_count * bytes_per_glyph)
+            .ok_or(FontError::Truncated)?;
+        let mut font = PsfFont {
+            glyphs,
+            glyph_count,
+            bytes_per_glyph,
+            width,
+            height,
+            unicode_table,
+            low_glyphs: [NO_GLYPH; 256],
+        };
+        match unicode_table {
+            Some(table) => {
+                for (index, c) in UnicodeEntries::new(table) {
+                    if let Some(slot) = font.low_glyphs.get_mut(c as usize)
+                        && *slot == NO_GLYPH
+                        && index < NO_GLYPH as usize
+                    {
+                        *slot = index as u16;
+                    }
+                }
+            }
+            None => {
+                for (index, slot) in font.low_glyphs.iter_mut().enumerate().take(glyph_count) {
+                    *slot = index as u16;
+                }
+            }
+        }
+        Ok(font)
+    }
+
+    pub fn glyph_size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    pub fn glyph_count(&self) -> usize {
+        self.glyph_count
+    }
+
+    fn glyph_index(&self, c: char) -> Option<usize> {
+        let index = if (c as usize) < 256 {
+            match self.low_glyphs[c as usize] {
+                NO_GLYPH => return None,
+                index => index as usize,
+            }
+        } else {
+            match self.unicode_table {
+                Some(table) => UnicodeEntries::new(table).find(|entry| entry.1 == c)?.0,
+                None => c as usize,
+            }
+        };
+        (index < self.glyph_count).then_some(index)
+    }
+
+    fn glyph(&self, index: usize) -> Glyph {
+        let start = index * self.bytes_per_glyph;
+        Glyph::Bitmap {
+            data: &self.glyphs[start..start + self.bytes_per_glyph],
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+/// Walks a PSF Unicode table, yielding `(glyph index, character)` for every
+/// single code point mapped to a glyph. Multi-character sequences are skipped.
+struct UnicodeEntries {
+    table: UnicodeTable,
+    position: usize,
+    glyph: usize,
+    in_sequence: bool,
+}
+
+impl UnicodeEntries {
+    fn new(table: UnicodeTable) -> Self {
+        UnicodeEntries {
+            table,
+            position: 0,
+            glyph: 0,
+            in_sequence: false,
+        }
+    }
+}
+
+impl Iterator for UnicodeEntries {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<(usize, char)> {
+        loop {
+            match self.table {
+                UnicodeTable::Psf1(table) => {
+                    let bytes = table.get(self.position..self.position + 2)?;
+                    self.position += 2;
+                    match u16::from_le_bytes([bytes[0], bytes[1]]) {
+                        0xFFFF => {
+                            self.glyph += 1;
+                            self.in_sequence = false;
+                        }
+                        0xFFFE => self.in_sequence = true,
+                        _ if self.in_sequence => {}
+                        value => {
+                            if let Some(c) = char::from_u32(value as u32) {
+                                return Some((self.glyph, c));
+                            }
+                        }
+                    }
+                }
+                UnicodeTable::Psf2(table) => {
+                    let lead = *table.get(self.position)?;
+                    match lead {
+                        0xFF => {
+                            self.position += 1;
+                            self.glyph += 1;
+                            self.in_sequence = false;
+                        }
+                        0xFE => {
+                            self.position += 1;
+                            self.in_sequence = true;
+                        }
+                        _ => {
+                            let len = match lead {
+                                0xF0..=0xF7 => 4,
+                                0xE0..=0xEF => 3,
+                                0xC0..=0xDF => 2,
+                                _ => 1,
+                            };
+                            let bytes = table.get(self.position..self.position + len)?;
+                            self.position += len;
+                            if self.in_sequence {
+                                continue;
+                            }
+                            if let Some(c) = core::str::from_utf8(bytes).ok().and_then(|s| s.chars().next()) {
+                                return Some((self.glyph, c));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
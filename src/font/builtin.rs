@@ -0,0 +1,966 @@
+//! The 8x8 font compiled into the crate, drawn in a 10x10 cell.
+
+pub(super) const FALLBACK: [[bool; 8]; 8] = [[true; 8]; 8];
+
+pub(super) fn glyph(c: char) -> Option<[[bool; 8]; 8]> {
+    let B = false;
+    let W = true;
+    let pixels = match c {
+        ' ' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        'a' => [
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, B],
+            [B, B, B, B, B, B, B, W],
+            [B, B, B, B, B, B, B, W],
+            [B, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, W, W],
+            [B, W, W, W, W, W, B, W],
+        ],
+        'b' => [
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, W, W, W, W, W, W, B],
+        ],
+        'c' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, W],
+        ],
+        'd' => [
+            [B, B, B, B, B, B, B, W],
+            [B, B, B, B, B, B, B, W],
+            [B, B, B, B, B, B, B, W],
+            [B, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [B, W, W, W, W, W, W, W],
+        ],
+        'e' => [
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, W, W, W, W, W, W, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, W],
+        ],
+        'f' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, W, W, W, W],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+        ],
+        'g' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, W],
+            [B, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, W],
+            [B, B, B, B, B, B, B, W],
+            [W, W, W, W, W, W, W, B],
+        ],
+        'h' => [
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+        ],
+        'i' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+        ],
+        'j' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, W, W, B, B],
+            [B, B, B, B, B, W, B, B],
+            [W, B, B, B, B, W, B, B],
+            [W, B, B, B, B, W, B, B],
+            [B, W, W, W, W, B, B, B],
+        ],
+        'k' => [
+            [B, B, B, B, B, B, B, B],
+            [W, B, B, B, B, W, W, B],
+            [W, B, B, B, W, B, B, B],
+            [W, B, B, W, B, B, B, B],
+            [W, B, W, B, B, B, B, B],
+            [W, W, B, B, B, B, B, B],
+            [W, B, W, B, B, B, B, B],
+            [W, B, B, W, W, B, B, B],
+        ],
+        'l' => [
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, B, W, W, W, W, W],
+        ],
+        'm' => [
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, B, W, W, W, B],
+            [W, B, B, W, B, B, B, W],
+            [W, B, B, W, B, B, B, W],
+            [W, B, B, W, B, B, B, W],
+            [W, B, B, W, B, B, B, W],
+            [W, B, B, W, B, B, B, W],
+            [W, B, B, W, B, B, B, W],
+        ],
+        'n' => [
+            [B, B, B, B, B, B, B, B],
+            [W, B, W, W, W, W, W, B],
+            [W, W, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+        ],
+        'o' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'p' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, W, W, W, W, W, W, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+        ],
+        'q' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [B, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, W],
+            [B, B, B, B, B, B, B, W],
+        ],
+        'r' => [
+            [B, B, B, B, B, B, B, B],
+            [W, B, W, W, W, W, W, W],
+            [W, W, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+        ],
+        's' => [
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, B, B, B, B, B, B, W],
+            [B, B, B, B, B, B, B, W],
+            [W, W, W, W, W, W, W, B],
+        ],
+        't' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, W, W, W, W],
+        ],
+        'u' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'v' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        'w' => [
+            [B, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [B, W, B, W, W, B, W, B],
+            [B, W, B, W, W, B, W, B],
+            [B, W, B, W, B, B, W, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+        ],
+        'x' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, W],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, W, W, B, B, W, W, B],
+            [W, B, B, B, B, B, B, W],
+        ],
+        'y' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, W],
+            [B, B, W, B, B, B, W, B],
+            [B, B, B, W, B, W, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, B, B, B, B, B],
+        ],
+        'z' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+        ],
+
+        'A' => [
+            [W, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+        ],
+        'B' => [
+            [W, W, W, W, W, W, W, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, W, W, W, W, W, W, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, W, W, W, W, W, W, B],
+        ],
+        'C' => [
+            [B, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, W],
+        ],
+        'D' => [
+            [W, W, W, W, W, W, B, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, W, B],
+            [W, W, W, W, W, W, B, B],
+        ],
+        'E' => [
+            [W, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+        ],
+        'F' => [
+            [W, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+        ],
+        'G' => [
+            [B, W, W, W, W, W, W, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, W, W, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [B, W, W, W, W, W, W, B],
+        ],
+        'H' => [
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+        ],
+        'I' => [
+            [W, W, W, W, W, W, W, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, W, W, W, B],
+        ],
+        'J' => [
+            [B, B, B, W, W, W, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, W, B, B],
+            [W, B, B, B, B, W, B, B],
+            [W, B, B, B, B, W, B, B],
+            [B, W, W, W, W, B, B, B],
+        ],
+        'K' => [
+            [W, B, B, B, W, W, B, B],
+            [W, B, B, W, B, B, B, B],
+            [W, B, W, B, B, B, B, B],
+            [W, W, B, B, B, B, B, B],
+            [W, B, W, B, B, B, B, B],
+            [W, B, B, W, B, B, B, B],
+            [W, B, B, B, W, B, B, B],
+            [W, B, B, B, B, W, B, B],
+        ],
+        'L' => [
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+        ],
+        'M' => [
+            [W, B, B, B, B, B, B, W],
+            [W, W, B, B, B, B, W, W],
+            [W, B, W, B, B, W, B, W],
+            [W, B, B, W, W, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+        ],
+        'N' => [
+            [W, B, B, B, B, B, B, W],
+            [W, W, B, B, B, B, B, W],
+            [W, B, W, B, B, B, B, W],
+            [W, B, B, W, B, B, B, W],
+            [W, B, B, B, W, B, B, W],
+            [W, B, B, B, B, W, B, W],
+            [W, B, B, B, B, B, W, W],
+            [W, B, B, B, B, B, B, W],
+        ],
+        'O' => [
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'P' => [
+            [W, W, W, W, W, W, W, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, W, W, W, W, W, W, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+        ],
+        'Q' => [
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [B, W, B, B, B, B, B, W],
+            [B, B, W, W, W, W, W, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, B, W, W],
+        ],
+        'R' => [
+            [W, W, W, W, W, W, W, B],
+            [W, B, B, B, B, B, B, W],
+            [W, W, B, B, B, B, B, W],
+            [W, W, W, W, W, W, W, B],
+            [W, B, W, B, B, B, B, B],
+            [W, B, B, W, W, B, B, B],
+            [W, B, B, B, B, W, B, B],
+            [W, B, B, B, B, B, W, B],
+        ],
+        'S' => [
+            [B, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, B, B, B, B, B, B, W],
+            [B, B, B, B, B, B, B, W],
+            [B, B, B, B, B, B, B, W],
+            [W, W, W, W, W, W, W, B],
+        ],
+        'T' => [
+            [W, W, W, W, W, W, W, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        'U' => [
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'V' => [
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        'W' => [
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, W, B, B, W, B],
+            [B, W, B, W, B, W, B, B],
+            [B, W, B, W, B, W, B, B],
+            [B, W, B, W, B, W, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+        ],
+        'X' => [
+            [W, B, B, B, B, B, B, W],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [W, B, B, B, B, B, B, W],
+        ],
+        'Y' => [
+            [W, B, B, B, B, B, W, B],
+            [B, W, B, B, B, W, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        'Z' => [
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+        ],
+
+        '0' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, W, B, B, W, B],
+            [B, W, B, W, B, B, W, B],
+            [B, W, B, B, W, B, W, B],
+            [B, W, B, B, W, B, W, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, W, W, B, B, B],
+        ],
+        '1' => [
+            [B, W, W, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        '2' => [
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [W, B, B, B, B, B, B, W],
+            [B, B, B, B, B, B, B, W],
+            [B, B, B, B, W, W, W, B],
+            [B, B, W, W, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+        ],
+        '3' => [
+            [B, W, W, W, W, W, B, B],
+            [W, B, B, B, B, B, W, B],
+            [B, B, B, B, B, B, B, W],
+            [B, B, W, W, W, W, W, B],
+            [B, B, B, B, B, B, B, W],
+            [B, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, W, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        '4' => [
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, W, B, B, W, B, B, B],
+            [W, B, B, B, W, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, B, W, B, B, B],
+        ],
+        '5' => [
+            [W, W, W, W, W, W, W, W],
+            [W, B, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, B, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, W, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        '6' => [
+            [B, B, W, W, W, W, W, W],
+            [B, W, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, B, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, B, W],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        '7' => [
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+        ],
+        '8' => [
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [B, W, W, W, W, W, W, B],
+        ],
+        '9' => [
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [W, B, B, B, B, B, B, W],
+            [B, W, B, B, B, B, B, W],
+            [B, B, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, W, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+
+        '-' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '_' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+        ],
+        '"' => [
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '{' => [
+            [B, B, B, B, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [W, W, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, W, W, B, B],
+        ],
+        '}' => [
+            [B, B, W, W, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, W, W, W],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, W, W, B, B, B, B],
+        ],
+        '[' => [
+            [B, B, W, W, W, W, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        ']' => [
+            [B, B, W, W, W, W, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        '(' => [
+            [B, B, B, B, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, W, W, B, B],
+        ],
+        ')' => [
+            [B, B, W, W, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, W, W, B, B, B, B],
+        ],
+        ',' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+        ],
+        '=' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        ':' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+        ],
+        '!' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '.' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+        ],
+        '#' => [
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+        ],
+        '$' => [
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [W, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, B, W, B, B, W, B],
+            [B, B, B, W, B, B, W, B],
+            [W, W, W, W, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '%' => [
+            [B, W, W, B, B, B, B, W],
+            [W, B, B, W, B, B, W, B],
+            [B, W, W, B, B, W, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, B, B, W, W, B],
+            [B, W, B, B, W, B, B, W],
+            [W, B, B, B, B, W, W, B],
+        ],
+        '&' => [
+            [B, B, W, W, W, B, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, W, B, B, B],
+            [B, B, W, W, B, B, B, B],
+            [B, W, B, B, W, B, B, W],
+            [W, B, B, B, B, W, W, B],
+            [W, B, B, B, B, W, W, B],
+            [B, W, W, W, W, B, B, W],
+        ],
+        '\'' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '*' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, B, B, W, B, B, W, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [W, B, B, W, B, B, W, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '+' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, W, W, W, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '/' => [
+            [B, B, B, B, B, B, B, W],
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+        ],
+        ';' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '<' => [
+            [B, B, B, B, B, W, W, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, B, B, B, B, B],
+            [B, W, W, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, B, B, W, W, B],
+        ],
+        '>' => [
+            [B, W, W, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, B, B, W, W, B],
+            [B, B, B, B, B, W, W, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, B, B, B, B, B],
+        ],
+        '?' => [
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+        ],
+        '@' => [
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [W, B, B, W, W, B, B, W],
+            [W, B, W, B, B, W, B, W],
+            [W, B, W, B, B, W, B, W],
+            [W, B, B, W, W, W, B, W],
+            [B, W, B, B, B, B, B, B],
+            [B, B, W, W, W, W, W, B],
+        ],
+        '\\' => [
+            [W, B, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, B, B, B, W],
+        ],
+        '^' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, W, B, B, B, W, B, B],
+            [W, B, B, B, B, B, W, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '`' => [
+            [B, B, W, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '|' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '~' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, B, B, B, W, B],
+            [W, B, B, W, B, B, W, B],
+            [W, B, B, B, W, W, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+
+        _ => return None,
+    };
+    Some(pixels)
+}
@@ -0,0 +1,1066 @@
+//! Glyphs for the IBM PC code page 437 repertoire, keyed by their Unicode code
+//! points. The characters shared with Latin-1 are drawn from `latin1`, which
+//! is built for either feature.
+
+/// The characters of the repertoire that are in the Latin-1 Supplement.
+const SHARED_WITH_LATIN1: &str = "¶§ÇüéâäàåçêëèïîìÄÅÉæÆôöòûùÿÖÜ¢£¥áíóúñÑªº¿¬½¼¡«»ßµ±÷°·²\u{A0}";
+
+pub(super) fn glyph(c: char) -> Option<[[bool; 8]; 8]> {
+    let B = false;
+    let W = true;
+    let pixels = match c {
+        '☺' => [
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [W, B, W, B, B, W, B, W],
+            [W, B, B, W, W, B, B, W],
+            [W, B, W, B, B, W, B, W],
+            [W, B, B, W, W, B, B, W],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        '☻' => [
+            [B, B, W, W, W, W, B, B],
+            [B, W, W, W, W, W, W, B],
+            [W, W, B, W, W, B, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, B, W, W, B, W, W],
+            [W, W, W, B, B, W, W, W],
+            [B, W, W, W, W, W, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        '♥' => [
+            [B, W, W, B, W, W, B, B],
+            [W, W, W, W, W, W, W, B],
+            [W, W, W, W, W, W, W, B],
+            [W, W, W, W, W, W, W, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '♦' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [W, W, W, W, W, W, W, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '♣' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, W, B, W, B, W, B, B],
+            [W, W, W, W, W, W, W, B],
+            [B, W, B, W, B, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, W, W, B, B, B],
+        ],
+        '♠' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [W, W, W, W, W, W, W, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, W, W, B, B, B],
+        ],
+        '•' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '◘' => [
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, B, B, B, B, W, W],
+            [W, B, B, B, B, B, B, W],
+            [W, W, B, B, B, B, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+        ],
+        '○' => [
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [W, B, B, B, B, B, B, W],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        '◙' => [
+            [W, W, W, W, W, W, W, W],
+            [W, W, B, B, B, B, W, W],
+            [W, B, B, W, W, B, B, W],
+            [W, B, W, W, W, W, B, W],
+            [W, B, B, W, W, B, B, W],
+            [W, W, B, B, B, B, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+        ],
+        '♂' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, W, W, W, B],
+            [B, B, B, B, B, W, W, B],
+            [B, W, W, W, W, B, W, B],
+            [W, B, B, B, W, B, B, B],
+            [W, B, B, B, W, B, B, B],
+            [W, B, B, B, W, B, B, B],
+            [B, W, W, W, B, B, B, B],
+        ],
+        '♀' => [
+            [B, B, W, W, W, B, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '♪' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, B, W, B, W, W, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [B, W, W, B, B, B, B, B],
+        ],
+        '♫' => [
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, W, B, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [W, W, W, B, B, B, W, B],
+            [B, W, B, B, B, W, W, B],
+            [B, B, B, B, W, W, W, B],
+            [B, B, B, B, B, W, B, B],
+        ],
+        '☼' => [
+            [B, B, B, W, B, B, B, B],
+            [W, B, B, W, B, B, W, B],
+            [B, W, B, B, B, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, B, B, B, W, B, B],
+            [W, B, B, W, B, B, W, B],
+        ],
+        '►' => [
+            [W, B, B, B, B, B, B, B],
+            [W, W, W, B, B, B, B, B],
+            [W, W, W, W, W, B, B, B],
+            [W, W, W, W, W, W, W, B],
+            [W, W, W, W, W, B, B, B],
+            [W, W, W, B, B, B, B, B],
+            [W, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '◄' => [
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, W, W, W, B],
+            [B, B, W, W, W, W, W, B],
+            [W, W, W, W, W, W, W, B],
+            [B, B, W, W, W, W, W, B],
+            [B, B, B, B, W, W, W, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '↕' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, W, B, W, B, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, B, W, B, W, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '‼' => [
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+        ],
+        '▬' => [
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '↨' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, W, B, W, B, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, B, W, B, W, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, W, W, W, B],
+        ],
+        '↑' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, W, B, W, B, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '↓' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, B, W, B, W, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '→' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, B, W, B],
+            [B, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '←' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '∟' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+        ],
+        '↔' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [W, W, W, W, W, W, W, W],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '▲' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, W, W, W, W, W, B],
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+        ],
+        '▼' => [
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [B, W, W, W, W, W, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, B, W, W, B, B, B],
+        ],
+        '⌂' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, W, W, W, W, B],
+        ],
+        '₧' => [
+            [B, W, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [W, W, W, W, W, W, W, W],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, W, W, W, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+        ],
+        'ƒ' => [
+            [B, B, B, B, W, W, B, B],
+            [B, B, B, W, B, B, W, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, B, B, W, B, B, B, B],
+            [B, W, W, B, B, B, B, B],
+        ],
+        '⌐' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '░' => [
+            [W, B, B, B, W, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, W, B, B, B, W, B],
+            [B, B, B, B, B, B, B, B],
+            [W, B, B, B, W, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, W, B, B, B, W, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '▒' => [
+            [W, B, W, B, W, B, W, B],
+            [B, W, B, W, B, W, B, W],
+            [W, B, W, B, W, B, W, B],
+            [B, W, B, W, B, W, B, W],
+            [W, B, W, B, W, B, W, B],
+            [B, W, B, W, B, W, B, W],
+            [W, B, W, B, W, B, W, B],
+            [B, W, B, W, B, W, B, W],
+        ],
+        '▓' => [
+            [B, W, W, W, B, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, B, W, W, W, B, W],
+            [W, W, W, W, W, W, W, W],
+            [B, W, W, W, B, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, B, W, W, W, B, W],
+            [W, W, W, W, W, W, W, W],
+        ],
+        '│' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '┤' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '╡' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '╢' => [
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [W, W, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+        ],
+        '╖' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+        ],
+        '╕' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '╣' => [
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [W, W, W, B, W, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [W, W, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+        ],
+        '║' => [
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+        ],
+        '╗' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [W, W, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+        ],
+        '╝' => [
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [W, W, W, B, W, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [W, W, W, W, W, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '╜' => [
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [W, W, W, W, W, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '╛' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '┐' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '└' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '┴' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '┬' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '├' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, W, W, W, W],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '─' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '┼' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '╞' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, W, W, W, W],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, W, W, W, W],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '╟' => [
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, W, W, W],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+        ],
+        '╚' => [
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, W, W, W],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '╔' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, W, W, W, W, W, W],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, W, W, W, W],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+        ],
+        '╩' => [
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [W, W, W, B, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '╦' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, B, W, W, W, W],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+        ],
+        '╠' => [
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, W, W, W],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, W, W, W, W],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+        ],
+        '═' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '╬' => [
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [W, W, W, B, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, B, W, W, W, W],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+        ],
+        '╧' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '╨' => [
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '╤' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '╥' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+        ],
+        '╙' => [
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '╘' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, W, W, W, W],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '╒' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, W, W, W, W],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, W, W, W, W],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '╓' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, W, W, W, W, W, W],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+        ],
+        '╫' => [
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+        ],
+        '╪' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '┘' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '┌' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, W, W, W, W],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '█' => [
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+        ],
+        '▄' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+        ],
+        '▌' => [
+            [W, W, W, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+            [W, W, W, W, B, B, B, B],
+        ],
+        '▐' => [
+            [B, B, B, B, W, W, W, W],
+            [B, B, B, B, W, W, W, W],
+            [B, B, B, B, W, W, W, W],
+            [B, B, B, B, W, W, W, W],
+            [B, B, B, B, W, W, W, W],
+            [B, B, B, B, W, W, W, W],
+            [B, B, B, B, W, W, W, W],
+            [B, B, B, B, W, W, W, W],
+        ],
+        '▀' => [
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [W, W, W, W, W, W, W, W],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        'α' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, W, W, W, B, W, B],
+            [B, W, B, B, B, W, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, W, W, B],
+            [B, W, B, B, W, B, W, B],
+            [B, B, W, W, B, B, W, B],
+        ],
+        'Γ' => [
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+        ],
+        'π' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+        ],
+        'Σ' => [
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+        ],
+        'σ' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, W, W, W, W, W, B],
+            [B, W, B, B, W, B, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'τ' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, W, B],
+            [B, B, B, B, W, W, B, B],
+        ],
+        'Φ' => [
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [W, B, B, W, B, B, W, B],
+            [W, B, B, W, B, B, W, B],
+            [W, B, B, W, B, B, W, B],
+            [W, B, B, W, B, B, W, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        'Θ' => [
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'Ω' => [
+            [B, W, W, W, W, W, B, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [B, W, W, B, W, W, B, B],
+            [B, B, W, B, W, B, B, B],
+            [W, W, W, B, W, W, W, B],
+        ],
+        'δ' => [
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        '∞' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, B, W, W, B, B],
+            [W, B, B, W, B, B, W, B],
+            [W, B, B, W, B, B, W, B],
+            [B, W, W, B, W, W, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        'φ' => [
+            [B, W, B, B, W, W, B, B],
+            [W, B, B, W, B, B, W, B],
+            [W, B, B, W, B, B, W, B],
+            [W, B, B, W, B, B, W, B],
+            [W, B, B, W, B, B, W, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        'ε' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        '∩' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+        ],
+        '≡' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '≥' => [
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, B, B, B, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, B, B, B, W, W, B],
+            [B, B, B, W, W, B, B, B],
+            [W, W, W, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, B],
+        ],
+        '≤' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, W, W, W, B],
+            [B, B, W, W, B, B, B, B],
+            [W, W, B, B, B, B, B, B],
+            [B, B, W, W, B, B, B, B],
+            [B, B, B, B, W, W, W, B],
+            [B, B, B, B, B, B, B, B],
+            [W, W, W, W, W, W, W, B],
+        ],
+        '⌠' => [
+            [B, B, B, B, W, W, B, B],
+            [B, B, B, W, B, B, W, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '⌡' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, B, B, W, B, B, B, B],
+            [B, W, W, B, B, B, B, B],
+        ],
+        '≈' => [
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, B, B, B, B, B],
+            [W, B, B, W, B, B, W, B],
+            [B, B, B, B, W, W, B, B],
+            [B, W, W, B, B, B, B, B],
+            [W, B, B, W, B, B, W, B],
+            [B, B, B, B, W, W, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '∙' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '√' => [
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [W, B, B, W, B, B, B, B],
+            [B, W, B, W, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+        ],
+        'ⁿ' => [
+            [B, B, W, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '■' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        _ if SHARED_WITH_LATIN1.contains(c) => return super::latin1::glyph(c),
+        _ => return None,
+    };
+    Some(pixels)
+}
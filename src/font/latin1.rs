@@ -0,0 +1,970 @@
+//! Latin-1 Supplement (U+00A0 to U+00FF) glyphs for the built-in font.
+
+pub(super) fn glyph(c: char) -> Option<[[bool; 8]; 8]> {
+    let B = false;
+    let W = true;
+    let pixels = match c {
+        '\u{A0}' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '¡' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '¢' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, W, B, W, B, W, B, B],
+            [B, W, B, W, B, B, B, B],
+            [B, W, B, W, B, B, B, B],
+            [B, W, B, W, B, W, B, B],
+            [B, B, W, W, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '£' => [
+            [B, B, B, W, W, W, B, B],
+            [B, B, W, B, B, B, W, B],
+            [B, B, W, B, B, B, B, B],
+            [B, W, W, W, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, W, W, B, B, B, W, B],
+            [W, W, B, W, W, W, B, B],
+        ],
+        '¤' => [
+            [B, B, B, B, B, B, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '¥' => [
+            [W, B, B, B, B, B, W, B],
+            [B, W, B, B, B, W, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '¦' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        '§' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, W, W, B, B, B],
+        ],
+        '¨' => [
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '©' => [
+            [B, B, W, W, W, B, B, B],
+            [B, W, B, B, B, W, B, B],
+            [W, B, B, W, B, B, W, B],
+            [W, B, W, B, W, B, W, B],
+            [W, B, W, B, W, B, W, B],
+            [W, B, B, W, B, B, W, B],
+            [B, W, B, B, B, W, B, B],
+            [B, B, W, W, W, B, B, B],
+        ],
+        'ª' => [
+            [B, B, W, W, W, B, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '«' => [
+            [B, B, B, W, B, B, W, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, W, B, B, B],
+            [W, B, B, W, B, B, B, B],
+            [B, W, B, B, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, W, B, B, W, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '¬' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '\u{AD}' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '®' => [
+            [B, B, W, W, W, B, B, B],
+            [B, W, B, B, B, W, B, B],
+            [W, B, B, W, B, B, W, B],
+            [W, B, W, B, W, B, W, B],
+            [W, B, W, W, B, B, W, B],
+            [W, B, W, B, W, B, W, B],
+            [B, W, B, B, B, W, B, B],
+            [B, B, W, W, W, B, B, B],
+        ],
+        '¯' => [
+            [B, W, W, W, W, W, W, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '°' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '±' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '²' => [
+            [B, B, W, W, B, B, B, B],
+            [B, W, B, B, W, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, W, W, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, W, W, W, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '³' => [
+            [B, B, W, W, B, B, B, B],
+            [B, W, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, W, B, B, W, B, B, B],
+            [B, B, W, W, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '´' => [
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        'µ' => [
+            [B, B, B, B, B, B, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, B, B, W, W, B],
+            [B, W, B, W, W, B, W, B],
+            [B, W, B, B, B, B, B, B],
+        ],
+        '¶' => [
+            [B, B, W, W, W, W, W, B],
+            [B, W, W, W, B, W, B, B],
+            [B, W, W, W, B, W, B, B],
+            [B, B, W, W, B, W, B, B],
+            [B, B, B, W, B, W, B, B],
+            [B, B, B, W, B, W, B, B],
+            [B, B, B, W, B, W, B, B],
+            [B, B, B, W, B, W, B, B],
+        ],
+        '·' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '¸' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, W, B, B, B],
+        ],
+        '¹' => [
+            [B, B, W, B, B, B, B, B],
+            [B, W, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, W, W, W, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        'º' => [
+            [B, B, W, W, B, B, B, B],
+            [B, W, B, B, W, B, B, B],
+            [B, W, B, B, W, B, B, B],
+            [B, B, W, W, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '»' => [
+            [W, B, B, W, B, B, B, B],
+            [B, W, B, B, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, W, B, B, W, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, W, B, B, B],
+            [W, B, B, W, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        '¼' => [
+            [B, W, B, B, B, B, B, B],
+            [W, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, W, B],
+            [W, W, W, B, B, W, W, B],
+            [B, B, B, B, W, B, W, B],
+            [B, B, B, W, B, B, W, B],
+            [B, B, B, W, W, B, W, B],
+            [B, B, B, B, B, W, W, B],
+        ],
+        '½' => [
+            [B, W, B, B, B, B, B, B],
+            [W, W, B, B, B, B, B, B],
+            [B, W, B, B, W, W, B, B],
+            [W, W, W, W, B, B, W, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, B, B, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, W, W, W, B],
+        ],
+        '¾' => [
+            [B, W, W, B, B, B, B, B],
+            [W, B, B, W, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [W, B, B, W, B, B, W, B],
+            [B, W, W, B, B, W, W, B],
+            [B, B, B, W, W, B, W, B],
+            [B, B, B, B, B, W, W, B],
+        ],
+        '¿' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'À' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+        ],
+        'Á' => [
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+        ],
+        'Â' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+        ],
+        'Ã' => [
+            [B, B, W, W, B, B, W, B],
+            [B, W, B, B, W, W, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+        ],
+        'Ä' => [
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+        ],
+        'Å' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+        ],
+        'Æ' => [
+            [B, W, W, B, W, W, W, B],
+            [W, B, B, W, B, B, B, B],
+            [W, B, B, W, B, B, B, B],
+            [W, B, B, W, W, W, B, B],
+            [W, W, W, W, B, B, B, B],
+            [W, B, B, W, B, B, B, B],
+            [W, B, B, W, B, B, B, B],
+            [W, B, B, W, W, W, W, B],
+        ],
+        'Ç' => [
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        'È' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, W, W, W, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+        ],
+        'É' => [
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, W, W, W, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+        ],
+        'Ê' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, W, W, W, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+        ],
+        'Ë' => [
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, W, W, W, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, W, W, W, W, W, B],
+        ],
+        'Ì' => [
+            [B, B, W, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        'Í' => [
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        'Î' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        'Ï' => [
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        'Ð' => [
+            [B, W, W, W, W, B, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [W, W, W, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, W, W, W, B, B, B],
+        ],
+        'Ñ' => [
+            [B, W, W, B, B, W, B, B],
+            [W, B, B, W, W, B, B, B],
+            [W, W, B, B, B, B, W, B],
+            [W, B, W, B, B, B, W, B],
+            [W, B, B, W, B, B, W, B],
+            [W, B, B, B, W, B, W, B],
+            [W, B, B, B, B, W, W, B],
+            [W, B, B, B, B, B, W, B],
+        ],
+        'Ò' => [
+            [B, B, W, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        'Ó' => [
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        'Ô' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, W, W, W, W, B, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        'Õ' => [
+            [B, W, W, B, B, W, B, B],
+            [W, B, B, W, W, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        'Ö' => [
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, W, W, W, W, B, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [W, B, B, B, B, B, W, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        '×' => [
+            [B, B, B, B, B, B, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        'Ø' => [
+            [B, B, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, W, W, B],
+            [B, W, B, B, W, B, W, B],
+            [B, W, B, W, B, B, W, B],
+            [B, W, W, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, B, B],
+        ],
+        'Ù' => [
+            [B, B, W, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'Ú' => [
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'Û' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'Ü' => [
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'Ý' => [
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        'Þ' => [
+            [B, W, B, B, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, W, W, W, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+        ],
+        'ß' => [
+            [B, B, W, W, W, B, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, W, B, B, B],
+            [B, W, B, W, B, B, B, B],
+            [B, W, B, B, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, W, W, W, B, B],
+        ],
+        'à' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, W, W, W, W, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, W, W, B],
+            [B, B, W, W, W, B, W, B],
+        ],
+        'á' => [
+            [B, B, B, B, B, W, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, W, W, W, W, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, W, W, B],
+            [B, B, W, W, W, B, W, B],
+        ],
+        'â' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, W, W, W, W, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, W, W, B],
+            [B, B, W, W, W, B, W, B],
+        ],
+        'ã' => [
+            [B, B, W, W, B, B, W, B],
+            [B, W, B, B, W, W, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, W, W, W, W, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, W, W, B],
+            [B, B, W, W, W, B, W, B],
+        ],
+        'ä' => [
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, W, W, W, W, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, W, W, B],
+            [B, B, W, W, W, B, W, B],
+        ],
+        'å' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, W, W, W, W, W, B],
+            [B, W, B, B, B, W, W, B],
+            [B, B, W, W, W, B, W, B],
+        ],
+        'æ' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, B, W, W, B, B],
+            [B, B, B, W, B, B, W, B],
+            [B, W, W, W, W, W, B, B],
+            [W, B, B, W, B, B, B, B],
+            [W, B, B, W, B, B, W, B],
+            [B, W, W, B, W, W, B, B],
+        ],
+        'ç' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+        ],
+        'è' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, W, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'é' => [
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'ê' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'ë' => [
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, W, W, W, W, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'ì' => [
+            [B, B, W, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        'í' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, B, B, B, B, B],
+            [B, B, W, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        'î' => [
+            [B, B, W, W, B, B, B, B],
+            [B, W, B, B, W, B, B, B],
+            [B, B, W, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        'ï' => [
+            [B, W, B, B, W, B, B, B],
+            [B, W, B, B, W, B, B, B],
+            [B, B, W, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+        ],
+        'ð' => [
+            [B, B, W, B, B, W, B, B],
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, B, B, B, B, W, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'ñ' => [
+            [B, B, W, W, B, B, W, B],
+            [B, W, B, B, W, W, B, B],
+            [B, W, B, W, W, W, B, B],
+            [B, W, W, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+        ],
+        'ò' => [
+            [B, B, W, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'ó' => [
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'ô' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'õ' => [
+            [B, B, W, W, B, B, W, B],
+            [B, W, B, B, W, W, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'ö' => [
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        '÷' => [
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, W, W, W, W, W, B, B],
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        'ø' => [
+            [B, B, B, B, B, B, B, B],
+            [B, B, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, W, B, W, B],
+            [B, W, B, W, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, B, B, B, B, B, B, B],
+        ],
+        'ù' => [
+            [B, B, W, B, B, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, B, W, W, W, B, W, B],
+        ],
+        'ú' => [
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, B, W, W, W, B, W, B],
+        ],
+        'û' => [
+            [B, B, B, W, W, B, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, B, W, W, W, B, W, B],
+        ],
+        'ü' => [
+            [B, B, W, B, W, B, B, B],
+            [B, B, W, B, W, B, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, W, B, B, B, W, B, B],
+            [B, B, W, W, W, B, W, B],
+        ],
+        'ý' => [
+            [B, B, B, B, W, B, B, B],
+            [B, B, B, W, B, B, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, W, W, B],
+            [B, B, W, W, W, B, W, B],
+            [B, B, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        'þ' => [
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, W, W, W, B, B],
+            [B, W, W, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, W, B, B, B, W, B],
+            [B, W, B, W, W, W, B, B],
+            [B, W, B, B, B, B, B, B],
+            [B, W, B, B, B, B, B, B],
+        ],
+        'ÿ' => [
+            [B, B, W, B, B, W, B, B],
+            [B, B, W, B, B, W, B, B],
+            [B, W, B, B, B, B, W, B],
+            [B, W, B, B, B, W, W, B],
+            [B, B, W, W, W, B, W, B],
+            [B, B, B, B, B, B, W, B],
+            [B, W, B, B, B, B, W, B],
+            [B, B, W, W, W, W, B, B],
+        ],
+        _ => return None,
+    };
+    Some(pixels)
+}
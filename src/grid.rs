@@ -0,0 +1,213 @@
+//! The text on screen as a grid of character cells, kept in caller-supplied
+//! storage so text can be redrawn, read back and moved without its pixels.
+
+use core::ops::BitOr;
+use core::ptr::null_mut;
+
+/// A set of text attributes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Attributes(u8);
+
+impl Attributes {
+    pub const NONE: Attributes = Attributes(0);
+    /// Swaps the foreground and background colors.
+    pub const INVERSE: Attributes = Attributes(1 << 0);
+    /// Thickens the glyph by drawing it again one pixel to the right.
+    pub const BOLD: Attributes = Attributes(1 << 1);
+    /// Slants the glyph to the right, more towards its top.
+    pub const ITALIC: Attributes = Attributes(1 << 2);
+    /// Draws a line along the bottom row of the glyph.
+    pub const UNDERLINE: Attributes = Attributes(1 << 3);
+    /// Draws a line through the middle of the glyph.
+    pub const STRIKETHROUGH: Attributes = Attributes(1 << 4);
+
+    pub const fn contains(self, other: Attributes) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn union(self, other: Attributes) -> Attributes {
+        Attributes(self.0 | other.0)
+    }
+
+    pub const fn difference(self, other: Attributes) -> Attributes {
+        Attributes(self.0 & !other.0)
+    }
+}
+
+impl BitOr for Attributes {
+    type Output = Attributes;
+
+    fn bitor(self, other: Attributes) -> Attributes {
+        self.union(other)
+    }
+}
+
+/// One character on screen with the colors and attributes it was printed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub character: char,
+    pub foreground: (u8, u8, u8),
+    pub background: (u8, u8, u8),
+    pub attributes: Attributes,
+}
+
+impl Cell {
+    /// A space, white on black.
+    pub const BLANK: Cell = Cell::new(' ', (255, 255, 255), (0, 0, 0), Attributes::NONE);
+
+    pub const fn new(
+        character: char,
+        foreground: (u8, u8, u8),
+        background: (u8, u8, u8),
+        attributes: Attributes,
+    ) -> Self {
+        Cell {
+            character,
+            foreground,
+            background,
+            attributes,
+        }
+    }
+
+    /// The colors to draw the glyph and the rest of the cell with.
+    pub fn colors(&self) -> ((u8, u8, u8), (u8, u8, u8)) {
+        if self.attributes.contains(Attributes::INVERSE) {
+            (self.background, self.foreground)
+        } else {
+            (self.foreground, self.background)
+        }
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Cell::BLANK
+    }
+}
+
+/// `columns * rows` cells in row-major order. Without storage the grid has no
+/// cells, and text only exists as pixels.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TextGrid {
+    cells: *mut Cell,
+    capacity: usize,
+    columns: usize,
+    rows: usize,
+}
+
+impl TextGrid {
+    pub(crate) const fn new() -> Self {
+        TextGrid {
+            cells: null_mut(),
+            capacity: 0,
+            columns: 0,
+            rows: 0,
+        }
+    }
+
+    pub(crate) fn is_attached(&self) -> bool {
+        !self.cells.is_null()
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Starts keeping cells in `storage`, filled with `blank`.
+    pub(crate) fn attach(&mut self, storage: &'static mut [Cell], size: (usize, usize), blank: Cell) {
+        storage.fill(blank);
+        *self = TextGrid {
+            cells: storage.as_mut_ptr(),
+            capacity: storage.len(),
+            columns: size.0,
+            rows: size.1,
+        };
+    }
+
+    fn cells_mut(&mut self) -> &mut [Cell] {
+        if self.cells.is_null() {
+            return &mut [];
+        }
+        unsafe { core::slice::from_raw_parts_mut(self.cells, self.capacity) }
+    }
+
+    fn cells(&self) -> &[Cell] {
+        if self.cells.is_null() {
+            return &[];
+        }
+        unsafe { core::slice::from_raw_parts(self.cells, self.capacity) }
+    }
+
+    pub(crate) fn get(&self, position: (usize, usize)) -> Option<Cell> {
+        if position.0 >= self.columns || position.1 >= self.rows {
+            return None;
+        }
+        self.cells().get(position.1 * self.columns + position.0).copied()
+    }
+
+    /// The cells of `row`, if there is storage for it.
+    pub(crate) fn row(&self, row: usize) -> Option<&[Cell]> {
+        if row >= self.rows {
+            return None;
+        }
+        self.cells().get(row * self.columns..(row + 1) * self.columns)
+    }
+
+    pub(crate) fn set(&mut self, position: (usize, usize), cell: Cell) {
+        if position.0 >= self.columns || position.1 >= self.rows {
+            return;
+        }
+        let columns = self.columns;
+        if let Some(slot) = self.cells_mut().get_mut(position.1 * columns + position.0) {
+            *slot = cell;
+        }
+    }
+
+    /// Sets the cells of `row` from column `start` up to (excluding) `end` to `blank`.
+    pub(crate) fn clear(&mut self, row: usize, start: usize, end: usize, blank: Cell) {
+        if row >= self.rows {
+            return;
+        }
+        let columns = self.columns;
+        let end = end.min(columns);
+        if start < end {
+            self.cells_mut()[row * columns + start..row * columns + end].fill(blank);
+        }
+    }
+
+    /// Moves every row up by one and clears the bottom row.
+    pub(crate) fn scroll_up(&mut self, blank: Cell) {
+        let (columns, rows) = (self.columns, self.rows);
+        if rows == 0 {
+            return;
+        }
+        self.cells_mut().copy_within(columns..rows * columns, 0);
+        self.clear(rows - 1, 0, columns, blank);
+    }
+
+    /// Changes the number of columns and rows, keeping the text in the top left
+    /// corner and filling new cells with `blank`.
+    pub(crate) fn resize(&mut self, size: (usize, usize), blank: Cell) {
+        if !self.is_attached() {
+            return;
+        }
+        let (old_columns, old_rows) = (self.columns, self.rows);
+        let (columns, rows) = size;
+        let kept = (old_columns.min(columns), old_rows.min(rows));
+        let cells = self.cells_mut();
+        // Rows move towards the end of the storage when they get wider, so
+        // copy from the last row then, and from the first one otherwise.
+        let copy_row = |cells: &mut [Cell], row: usize| {
+            cells.copy_within(row * old_columns..row * old_columns + kept.0, row * columns);
+            cells[row * columns + kept.0..(row + 1) * columns].fill(blank);
+        };
+        if columns > old_columns {
+            (0..kept.1).rev().for_each(|row| copy_row(cells, row));
+        } else {
+            (0..kept.1).for_each(|row| copy_row(cells, row));
+        }
+        cells[kept.1 * columns..rows * columns].fill(blank);
+        self.columns = columns;
+        self.rows = rows;
+    }
+}
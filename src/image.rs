@@ -0,0 +1,83 @@
+//! Decoders for images embedded in the kernel, drawn with `DisplayTextManager::blit`.
+
+mod bmp;
+#[cfg(feature = "png")]
+mod png;
+#[cfg(feature = "qoi")]
+mod qoi;
+#[cfg(feature = "tga")]
+mod tga;
+
+pub use bmp::Bmp;
+#[cfg(feature = "png")]
+pub use png::Png;
+#[cfg(feature = "qoi")]
+pub use qoi::Qoi;
+#[cfg(feature = "tga")]
+pub use tga::Tga;
+
+use crate::{CompositeOp, DisplayTextManager, Rgba};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageError {
+    /// The data does not start with the signature of the format.
+    InvalidMagic,
+    /// The data ends before the image does.
+    Truncated,
+    /// The image uses a feature or variant of the format that is not supported.
+    Unsupported,
+    /// The image data contradicts itself, for example a run past the end of a row.
+    Corrupt,
+    /// The scratch buffer given to the decoder is too small for the image.
+    ScratchTooSmall,
+}
+
+/// A decodable image.
+pub trait Image {
+    /// Width and height in pixels.
+    fn size(&self) -> (usize, usize);
+
+    /// Calls `pixel` with the position and color of the image's pixels. Pixels
+    /// the image leaves undefined, such as those skipped by BMP run-length
+    /// encoding, are not reported.
+    fn decode(&self, pixel: impl FnMut((usize, usize), Rgba)) -> Result<(), ImageError>;
+}
+
+impl DisplayTextManager {
+    /// Draws `image` with its top left corner at `position`, clipped to the
+    /// screen. Translucent pixels are blended over what is already there.
+    ///
+    /// When decoding fails part way, the pixels decoded so far stay drawn.
+    pub fn blit(&mut self, image: &impl Image, position: (isize, isize)) -> Result<(), ImageError> {
+        self.without_cursor(|manager| {
+            let (width, height) = (manager.frame_info.width, manager.frame_info.height);
+            let result = image.decode(|offset, color| {
+                let x = position.0.saturating_add_unsigned(offset.0);
+                let y = position.1.saturating_add_unsigned(offset.1);
+                if x < 0 || y < 0 || x as usize >= width || y as usize >= height {
+                    return;
+                }
+                let point = (x as usize, y as usize);
+                match color.a {
+                    0 => {}
+                    255 => manager.set_pixel_uncheck(point, color.rgb()),
+                    alpha => manager.blend_pixel_uncheck(point, color.rgb(), alpha as f32 / 255.0, CompositeOp::SourceOver),
+                }
+            });
+            manager.add_clipped_damage(position, image.size());
+            result
+        })
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, ImageError> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .ok_or(ImageError::Truncated)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, ImageError> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .ok_or(ImageError::Truncated)
+}
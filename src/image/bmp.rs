@@ -0,0 +1,255 @@
+//! Windows bitmaps: 1, 4, 8, 16, 24 and 32 bits per pixel, uncompressed,
+//! with bit field masks, or run-length encoded (RLE8 and RLE4).
+
+use super::{Image, ImageError, read_u16, read_u32};
+use crate::Rgba;
+
+const FILE_HEADER_SIZE: usize = 14;
+const CORE_HEADER_SIZE: usize = 12;
+const INFO_HEADER_SIZE: usize = 40;
+/// The first header version that stores an alpha mask.
+const V3_HEADER_SIZE: usize = 56;
+
+const BI_RGB: u32 = 0;
+const BI_RLE8: u32 = 1;
+const BI_RLE4: u32 = 2;
+const BI_BITFIELDS: u32 = 3;
+const BI_ALPHABITFIELDS: u32 = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Compression {
+    None,
+    Rle8,
+    Rle4,
+}
+
+/// Where a channel lives in a 16 or 32 bit pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Mask(u32);
+
+impl Mask {
+    fn extract(self, pixel: u32) -> Option<u8> {
+        if self.0 == 0 {
+            return None;
+        }
+        let shift = self.0.trailing_zeros();
+        let max = (self.0 >> shift) as u64;
+        Some((((pixel & self.0) >> shift) as u64 * 255 / max) as u8)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Bmp<'a> {
+    width: usize,
+    height: usize,
+    top_down: bool,
+    bits_per_pixel: usize,
+    compression: Compression,
+    /// Red, green, blue and alpha masks for 16 and 32 bit pixels.
+    masks: [Mask; 4],
+    palette: &'a [u8],
+    palette_entry_size: usize,
+    pixels: &'a [u8],
+}
+
+impl<'a> Bmp<'a> {
+    pub fn parse(data: &'a [u8]) -> Result<Bmp<'a>, ImageError> {
+        if !data.starts_with(b"BM") {
+            return Err(ImageError::InvalidMagic);
+        }
+        let pixels_offset = read_u32(data, 10)? as usize;
+        let header_size = read_u32(data, 14)? as usize;
+        let (width, height, bits_per_pixel, compression, colors_used) = if header_size == CORE_HEADER_SIZE {
+            (read_u16(data, 18)? as i32, read_u16(data, 20)? as i32, read_u16(data, 24)?, BI_RGB, 0)
+        } else if header_size >= INFO_HEADER_SIZE {
+            (
+                read_u32(data, 18)? as i32,
+                read_u32(data, 22)? as i32,
+                read_u16(data, 28)?,
+                read_u32(data, 30)?,
+                read_u32(data, 46)? as usize,
+            )
+        } else {
+            return Err(ImageError::Unsupported);
+        };
+        let bits_per_pixel = bits_per_pixel as usize;
+        if width <= 0 || height == 0 || !matches!(bits_per_pixel, 1 | 4 | 8 | 16 | 24 | 32) {
+            return Err(ImageError::Unsupported);
+        }
+        let top_down = height < 0;
+
+        // Masks follow an info header, but are part of the later header versions.
+        let mut header_end = FILE_HEADER_SIZE + header_size;
+        let mask_at = |index: usize| read_u32(data, FILE_HEADER_SIZE + INFO_HEADER_SIZE + index * 4).map(Mask);
+        let (compression, masks) = match (compression, bits_per_pixel) {
+            (BI_RGB, 16) => (Compression::None, [Mask(0x7C00), Mask(0x03E0), Mask(0x001F), Mask(0)]),
+            (BI_RGB, _) => (Compression::None, [Mask(0x00FF_0000), Mask(0x0000_FF00), Mask(0x0000_00FF), Mask(0)]),
+            (BI_BITFIELDS | BI_ALPHABITFIELDS, 16 | 32) => {
+                let has_alpha = compression == BI_ALPHABITFIELDS || header_size >= V3_HEADER_SIZE;
+                if header_size == INFO_HEADER_SIZE {
+                    header_end += if has_alpha { 16 } else { 12 };
+                }
+                let alpha = if has_alpha { mask_at(3)? } else { Mask(0) };
+                (Compression::None, [mask_at(0)?, mask_at(1)?, mask_at(2)?, alpha])
+            }
+            (BI_RLE8, 8) if !top_down => (Compression::Rle8, [Mask(0); 4]),
+            (BI_RLE4, 4) if !top_down => (Compression::Rle4, [Mask(0); 4]),
+            _ => return Err(ImageError::Unsupported),
+        };
+
+        let palette_entry_size = if header_size == CORE_HEADER_SIZE { 3 } else { 4 };
+        let palette = if bits_per_pixel <= 8 {
+            let max_colors = 1 << bits_per_pixel;
+            let colors = if colors_used == 0 || colors_used > max_colors { max_colors } else { colors_used };
+            // Writers that leave the color count at zero may still store fewer
+            // colors; the palette cannot run into the pixels.
+            let end = (header_end + colors * palette_entry_size).min(pixels_offset);
+            data.get(header_end..end.max(header_end)).ok_or(ImageError::Truncated)?
+        } else {
+            &[]
+        };
+
+        let bmp = Bmp {
+            width: width as usize,
+            height: height.unsigned_abs() as usize,
+            top_down,
+            bits_per_pixel,
+            compression,
+            masks,
+            palette,
+            palette_entry_size,
+            pixels: data.get(pixels_offset..).ok_or(ImageError::Truncated)?,
+        };
+        if compression == Compression::None {
+            let len = bmp.row_len().checked_mul(bmp.height).ok_or(ImageError::Unsupported)?;
+            if bmp.pixels.len() < len {
+                return Err(ImageError::Truncated);
+            }
+        }
+        Ok(bmp)
+    }
+
+    /// Bytes per row of uncompressed pixels, which are padded to 4 bytes.
+    fn row_len(&self) -> usize {
+        (self.width * self.bits_per_pixel).div_ceil(32) * 4
+    }
+
+    /// Palette entry `index`, black if the palette is shorter.
+    fn palette_color(&self, index: usize) -> Rgba {
+        match self.palette.get(index * self.palette_entry_size..index * self.palette_entry_size + 3) {
+            Some(entry) => Rgba::new(entry[2], entry[1], entry[0], 255),
+            None => Rgba::new(0, 0, 0, 255),
+        }
+    }
+
+    fn color_at(&self, row: &[u8], x: usize) -> Rgba {
+        match self.bits_per_pixel {
+            1 | 4 | 8 => {
+                let bit = x * self.bits_per_pixel;
+                let shift = 8 - self.bits_per_pixel - bit % 8;
+                let index = (row[bit / 8] >> shift) & ((1u16 << self.bits_per_pixel) - 1) as u8;
+                self.palette_color(index as usize)
+            }
+            24 => Rgba::new(row[x * 3 + 2], row[x * 3 + 1], row[x * 3], 255),
+            _ => {
+                let pixel = if self.bits_per_pixel == 16 {
+                    u16::from_le_bytes([row[x * 2], row[x * 2 + 1]]) as u32
+                } else {
+                    u32::from_le_bytes([row[x * 4], row[x * 4 + 1], row[x * 4 + 2], row[x * 4 + 3]])
+                };
+                let [red, green, blue, alpha] = self.masks.map(|mask| mask.extract(pixel));
+                Rgba::new(
+                    red.unwrap_or(0),
+                    green.unwrap_or(0),
+                    blue.unwrap_or(0),
+                    alpha.unwrap_or(255),
+                )
+            }
+        }
+    }
+
+    /// Runs RLE8 or RLE4 data. Rows are stored bottom up, and pixels skipped
+    /// by end-of-line and delta codes are left undefined.
+    fn decode_rle(&self, mut pixel: impl FnMut((usize, usize), Rgba)) -> Result<(), ImageError> {
+        let rle4 = self.compression == Compression::Rle4;
+        let data = self.pixels;
+        let (mut x, mut row) = (0, 0);
+        let mut put = |x: usize, row: usize, index: u8| {
+            if x < self.width && row < self.height {
+                pixel((x, self.height - 1 - row), self.palette_color(index as usize));
+            }
+        };
+        let mut position = 0;
+        // Some encoders leave out the final end-of-bitmap code.
+        while position + 1 < data.len() && row < self.height {
+            let (count, value) = (data[position] as usize, data[position + 1]);
+            position += 2;
+            if count > 0 {
+                for i in 0..count {
+                    let index = if !rle4 {
+                        value
+                    } else if i % 2 == 0 {
+                        value >> 4
+                    } else {
+                        value & 0x0F
+                    };
+                    put(x, row, index);
+                    x += 1;
+                }
+                continue;
+            }
+            match value {
+                0 => {
+                    x = 0;
+                    row += 1;
+                }
+                1 => return Ok(()),
+                2 => {
+                    let delta = data.get(position..position + 2).ok_or(ImageError::Truncated)?;
+                    x += delta[0] as usize;
+                    row += delta[1] as usize;
+                    position += 2;
+                }
+                count => {
+                    let count = count as usize;
+                    let len = if rle4 { count.div_ceil(2) } else { count };
+                    let run = data.get(position..position + len).ok_or(ImageError::Truncated)?;
+                    for i in 0..count {
+                        let index = if !rle4 {
+                            run[i]
+                        } else if i % 2 == 0 {
+                            run[i / 2] >> 4
+                        } else {
+                            run[i / 2] & 0x0F
+                        };
+                        put(x, row, index);
+                        x += 1;
+                    }
+                    // Absolute runs are padded to a 16-bit boundary.
+                    position += len.next_multiple_of(2);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Image for Bmp<'_> {
+    fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    fn decode(&self, mut pixel: impl FnMut((usize, usize), Rgba)) -> Result<(), ImageError> {
+        if self.compression != Compression::None {
+            return self.decode_rle(pixel);
+        }
+        let row_len = self.row_len();
+        for (index, row) in self.pixels.chunks_exact(row_len).take(self.height).enumerate() {
+            let y = if self.top_down { index } else { self.height - 1 - index };
+            for x in 0..self.width {
+                pixel((x, y), self.color_at(row, x));
+            }
+        }
+        Ok(())
+    }
+}
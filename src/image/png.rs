@@ -0,0 +1,368 @@
+//! PNG images of every color type and bit depth, plain or Adam7 interlaced,
+//! with transparency from `tRNS`. Rows are decompressed, unfiltered and drawn
+//! one at a time, so decoding only needs a scratch buffer of
+//! `Png::scratch_size` bytes: the inflate window and two rows.
+
+mod inflate;
+
+use core::cell::RefCell;
+
+use super::{Image, ImageError};
+use crate::Rgba;
+use inflate::{WINDOW_SIZE, inflate};
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+
+const GRAYSCALE: u8 = 0;
+const TRUECOLOR: u8 = 2;
+const INDEXED: u8 = 3;
+const GRAYSCALE_ALPHA: u8 = 4;
+const TRUECOLOR_ALPHA: u8 = 6;
+
+/// Offset and spacing of the pixels of each Adam7 pass: `(x, y, step_x, step_y)`.
+const ADAM7: [(usize, usize, usize, usize); 7] = [
+    (0, 0, 8, 8),
+    (4, 0, 8, 8),
+    (0, 4, 4, 8),
+    (2, 0, 4, 4),
+    (0, 2, 2, 4),
+    (1, 0, 2, 2),
+    (0, 1, 1, 2),
+];
+const NOT_INTERLACED: [(usize, usize, usize, usize); 1] = [(0, 0, 1, 1)];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Header {
+    width: usize,
+    height: usize,
+    bit_depth: u8,
+    color_type: u8,
+    interlaced: bool,
+}
+
+impl Header {
+    fn channels(&self) -> usize {
+        match self.color_type {
+            TRUECOLOR => 3,
+            GRAYSCALE_ALPHA => 2,
+            TRUECOLOR_ALPHA => 4,
+            _ => 1,
+        }
+    }
+
+    fn bits_per_pixel(&self) -> usize {
+        self.channels() * self.bit_depth as usize
+    }
+
+    /// Bytes in a row of `width` pixels, without the filter type.
+    fn row_len(&self, width: usize) -> usize {
+        (width * self.bits_per_pixel()).div_ceil(8)
+    }
+}
+
+#[derive(Debug)]
+pub struct Png<'a> {
+    header: Header,
+    data: &'a [u8],
+    palette: &'a [u8],
+    transparency: &'a [u8],
+    /// Offset of the first `IDAT` chunk.
+    image_data: usize,
+    scratch: RefCell<&'a mut [u8]>,
+}
+
+impl<'a> Png<'a> {
+    /// Parses a PNG file that will be decoded with the help of `scratch`, which
+    /// must hold at least `Png::scratch_size(data)` bytes.
+    pub fn parse(data: &'a [u8], scratch: &'a mut [u8]) -> Result<Png<'a>, ImageError> {
+        let header = parse_header(data)?;
+        if scratch.len() < scratch_size(&header)? {
+            return Err(ImageError::ScratchTooSmall);
+        }
+        let mut palette: &[u8] = &[];
+        let mut transparency: &[u8] = &[];
+        let mut image_data = None;
+        let mut offset = SIGNATURE.len();
+        loop {
+            let (kind, body, next) = chunk_at(data, offset)?;
+            match &kind {
+                b"PLTE" => palette = body,
+                b"tRNS" => transparency = body,
+                b"IDAT" if image_data.is_none() => image_data = Some(offset),
+                b"IEND" => break,
+                _ => {}
+            }
+            offset = next;
+        }
+        if header.color_type == INDEXED && palette.is_empty() {
+            return Err(ImageError::Corrupt);
+        }
+        Ok(Png {
+            header,
+            data,
+            palette,
+            transparency,
+            image_data: image_data.ok_or(ImageError::Truncated)?,
+            scratch: RefCell::new(scratch),
+        })
+    }
+
+    /// Bytes of scratch space needed to decode `data`.
+    pub fn scratch_size(data: &[u8]) -> Result<usize, ImageError> {
+        scratch_size(&parse_header(data)?)
+    }
+
+    /// Sample `index` of a row, at the image's bit depth.
+    fn sample(&self, row: &[u8], index: usize) -> u16 {
+        match self.header.bit_depth {
+            16 => u16::from_be_bytes([row[index * 2], row[index * 2 + 1]]),
+            8 => row[index] as u16,
+            depth => {
+                let bit = index * depth as usize;
+                ((row[bit / 8] >> (8 - depth as usize - bit % 8)) & ((1 << depth) - 1)) as u16
+            }
+        }
+    }
+
+    /// Scales a sample to 8 bits.
+    fn to_u8(&self, sample: u16) -> u8 {
+        match self.header.bit_depth {
+            16 => (sample >> 8) as u8,
+            8 => sample as u8,
+            depth => (sample as u32 * 255 / ((1 << depth) - 1)) as u8,
+        }
+    }
+
+    /// The color key from `tRNS` for grayscale or truecolor images: sample
+    /// `channel` of the color that is fully transparent.
+    fn transparent_sample(&self, channel: usize) -> Option<u16> {
+        let bytes = self.transparency.get(channel * 2..channel * 2 + 2)?;
+        Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn color(&self, row: &[u8], x: usize) -> Rgba {
+        let channels = self.header.channels();
+        let sample = |channel: usize| self.sample(row, x * channels + channel);
+        match self.header.color_type {
+            GRAYSCALE => {
+                let gray = sample(0);
+                let alpha = if self.transparent_sample(0) == Some(gray) { 0 } else { 255 };
+                let gray = self.to_u8(gray);
+                Rgba::new(gray, gray, gray, alpha)
+            }
+            TRUECOLOR => {
+                let (red, green, blue) = (sample(0), sample(1), sample(2));
+                let key = (self.transparent_sample(0), self.transparent_sample(1), self.transparent_sample(2));
+                let alpha = if key == (Some(red), Some(green), Some(blue)) { 0 } else { 255 };
+                Rgba::new(self.to_u8(red), self.to_u8(green), self.to_u8(blue), alpha)
+            }
+            INDEXED => {
+                let index = sample(0) as usize;
+                let alpha = self.transparency.get(index).copied().unwrap_or(255);
+                match self.palette.get(index * 3..index * 3 + 3) {
+                    Some(entry) => Rgba::new(entry[0], entry[1], entry[2], alpha),
+                    None => Rgba::new(0, 0, 0, 255),
+                }
+            }
+            GRAYSCALE_ALPHA => {
+                let gray = self.to_u8(sample(0));
+                Rgba::new(gray, gray, gray, self.to_u8(sample(1)))
+            }
+            _ => Rgba::new(
+                self.to_u8(sample(0)),
+                self.to_u8(sample(1)),
+                self.to_u8(sample(2)),
+                self.to_u8(sample(3)),
+            ),
+        }
+    }
+}
+
+impl Image for Png<'_> {
+    fn size(&self) -> (usize, usize) {
+        (self.header.width, self.header.height)
+    }
+
+    fn decode(&self, mut pixel: impl FnMut((usize, usize), Rgba)) -> Result<(), ImageError> {
+        let mut scratch = self.scratch.borrow_mut();
+        let row_len = self.header.row_len(self.header.width);
+        let (window, rows) = scratch.split_at_mut(WINDOW_SIZE);
+        let (mut previous, rows) = rows.split_at_mut(row_len);
+        let mut current = &mut rows[..row_len];
+        // Filters work on whole bytes, comparing each with the same byte of the pixel before.
+        let filter_distance = self.header.bits_per_pixel().div_ceil(8);
+
+        let (width, height) = (self.header.width, self.header.height);
+        let passes: &[(usize, usize, usize, usize)] = if self.header.interlaced { &ADAM7 } else { &NOT_INTERLACED };
+        let pass_size = |pass: &(usize, usize, usize, usize)| {
+            let (x, y, step_x, step_y) = *pass;
+            (width.saturating_sub(x).div_ceil(step_x), height.saturating_sub(y).div_ceil(step_y))
+        };
+        // Empty passes have no rows in the stream, not even filter types.
+        let mut passes = passes.iter().filter(|pass| {
+            let (pass_width, pass_height) = pass_size(pass);
+            pass_width > 0 && pass_height > 0
+        });
+        let mut pass = passes.next();
+        let (mut row, mut column, mut filter) = (0, 0, None);
+        previous.fill(0);
+
+        inflate(ImageData::new(self.data, self.image_data), window, |byte| {
+            let Some(current_pass) = pass else {
+                // Data past the last row is ignored.
+                return Ok(());
+            };
+            let Some(filter_type) = filter else {
+                filter = Some(byte);
+                return Ok(());
+            };
+            let (pass_width, pass_height) = pass_size(current_pass);
+            let pass_row_len = self.header.row_len(pass_width);
+            current[column] = byte;
+            column += 1;
+            if column < pass_row_len {
+                return Ok(());
+            }
+            unfilter(
+                filter_type,
+                &mut current[..pass_row_len],
+                &previous[..pass_row_len],
+                filter_distance,
+            )?;
+            let (start_x, start_y, step_x, step_y) = *current_pass;
+            for x in 0..pass_width {
+                pixel(
+                    (start_x + x * step_x, start_y + row * step_y),
+                    self.color(current, x),
+                );
+            }
+            core::mem::swap(&mut previous, &mut current);
+            (column, filter) = (0, None);
+            row += 1;
+            if row == pass_height {
+                row = 0;
+                pass = passes.next();
+                previous.fill(0);
+            }
+            Ok(())
+        })?;
+        if pass.is_some() {
+            return Err(ImageError::Truncated);
+        }
+        Ok(())
+    }
+}
+
+fn parse_header(data: &[u8]) -> Result<Header, ImageError> {
+    if !data.starts_with(&SIGNATURE) {
+        return Err(ImageError::InvalidMagic);
+    }
+    let (kind, body, _) = chunk_at(data, SIGNATURE.len())?;
+    if &kind != b"IHDR" || body.len() < 13 {
+        return Err(ImageError::Corrupt);
+    }
+    let header = Header {
+        width: u32::from_be_bytes([body[0], body[1], body[2], body[3]]) as usize,
+        height: u32::from_be_bytes([body[4], body[5], body[6], body[7]]) as usize,
+        bit_depth: body[8],
+        color_type: body[9],
+        interlaced: body[12] == 1,
+    };
+    let depth_supported = match header.color_type {
+        GRAYSCALE => matches!(header.bit_depth, 1 | 2 | 4 | 8 | 16),
+        INDEXED => matches!(header.bit_depth, 1 | 2 | 4 | 8),
+        TRUECOLOR | GRAYSCALE_ALPHA | TRUECOLOR_ALPHA => matches!(header.bit_depth, 8 | 16),
+        _ => false,
+    };
+    if header.width == 0 || header.height == 0 || !depth_supported || body[10] != 0 || body[11] != 0 || body[12] > 1 {
+        return Err(ImageError::Unsupported);
+    }
+    Ok(header)
+}
+
+fn scratch_size(header: &Header) -> Result<usize, ImageError> {
+    header
+        .width
+        .checked_mul(header.bits_per_pixel())
+        .and_then(|bits| bits.div_ceil(8).checked_mul(2))
+        .and_then(|rows| rows.checked_add(WINDOW_SIZE))
+        .ok_or(ImageError::Unsupported)
+}
+
+/// The chunk at `offset`: its type, its data and the offset of the next chunk.
+fn chunk_at(data: &[u8], offset: usize) -> Result<([u8; 4], &[u8], usize), ImageError> {
+    let header = data.get(offset..offset + 8).ok_or(ImageError::Truncated)?;
+    let len = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+    let kind = [header[4], header[5], header[6], header[7]];
+    let body = data.get(offset + 8..offset + 8 + len).ok_or(ImageError::Truncated)?;
+    // Skip the CRC.
+    Ok((kind, body, offset + 8 + len + 4))
+}
+
+/// The bytes of consecutive `IDAT` chunks, which together form one zlib stream.
+struct ImageData<'a> {
+    data: &'a [u8],
+    chunk: &'a [u8],
+    next: usize,
+}
+
+impl<'a> ImageData<'a> {
+    fn new(data: &'a [u8], first_chunk: usize) -> Self {
+        ImageData {
+            data,
+            chunk: &[],
+            next: first_chunk,
+        }
+    }
+}
+
+impl Iterator for ImageData<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        while self.chunk.is_empty() {
+            match chunk_at(self.data, self.next) {
+                Ok((kind, body, next)) if &kind == b"IDAT" => {
+                    self.chunk = body;
+                    self.next = next;
+                }
+                _ => return None,
+            }
+        }
+        let (byte, rest) = self.chunk.split_first()?;
+        self.chunk = rest;
+        Some(*byte)
+    }
+}
+
+/// Undoes the filter a row was stored with, given the unfiltered row above.
+fn unfilter(filter_type: u8, row: &mut [u8], previous: &[u8], distance: usize) -> Result<(), ImageError> {
+    for i in 0..row.len() {
+        let left = if i >= distance { row[i - distance] } else { 0 };
+        let up = previous[i];
+        let up_left = if i >= distance { previous[i - distance] } else { 0 };
+        let prediction = match filter_type {
+            0 => 0,
+            1 => left,
+            2 => up,
+            3 => ((left as u16 + up as u16) / 2) as u8,
+            4 => paeth(left, up, up_left),
+            _ => return Err(ImageError::Corrupt),
+        };
+        row[i] = row[i].wrapping_add(prediction);
+    }
+    Ok(())
+}
+
+/// Whichever of the three neighbours is closest to `left + up - up_left`.
+fn paeth(left: u8, up: u8, up_left: u8) -> u8 {
+    let estimate = left as i16 + up as i16 - up_left as i16;
+    let distance = |value: u8| (estimate - value as i16).abs();
+    if distance(left) <= distance(up) && distance(left) <= distance(up_left) {
+        left
+    } else if distance(up) <= distance(up_left) {
+        up
+    } else {
+        up_left
+    }
+}
@@ -0,0 +1,287 @@
+//! zlib (RFC 1950) and DEFLATE (RFC 1951) decompression that hands out bytes
+//! as soon as they are decoded, keeping only the 32 KiB window back
+//! references need. Huffman codes are decoded a bit at a time, which keeps
+//! the tables small at the cost of speed.
+
+use crate::ImageError;
+
+/// Bytes of output that back references can reach.
+pub(super) const WINDOW_SIZE: usize = 32 * 1024;
+
+const MAX_CODE_LENGTH: usize = 15;
+const LITERAL_LENGTH_CODES: usize = 288;
+const DISTANCE_CODES: usize = 30;
+const END_OF_BLOCK: u16 = 256;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258,
+];
+const LENGTH_EXTRA_BITS: [u8; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145,
+    8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA_BITS: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13,
+];
+/// The order in which a dynamic block lists the code lengths of its code length code.
+const CODE_LENGTH_ORDER: [usize; 19] = [16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15];
+
+/// Reads bits least significant first, as DEFLATE packs them.
+struct BitReader<I> {
+    bytes: I,
+    buffer: u32,
+    count: u32,
+}
+
+impl<I: Iterator<Item = u8>> BitReader<I> {
+    fn bits(&mut self, count: u32) -> Result<u32, ImageError> {
+        while self.count < count {
+            let byte = self.bytes.next().ok_or(ImageError::Truncated)?;
+            self.buffer |= (byte as u32) << self.count;
+            self.count += 8;
+        }
+        let value = self.buffer & ((1 << count) - 1);
+        self.buffer >>= count;
+        self.count -= count;
+        Ok(value)
+    }
+
+    /// Drops the rest of the current byte.
+    fn align(&mut self) {
+        self.buffer = 0;
+        self.count = 0;
+    }
+}
+
+/// A canonical Huffman code, stored as the number of codes of each length
+/// and the symbols sorted by code.
+struct Huffman {
+    counts: [u16; MAX_CODE_LENGTH + 1],
+    symbols: [u16; LITERAL_LENGTH_CODES],
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Result<Huffman, ImageError> {
+        let mut huffman = Huffman {
+            counts: [0; MAX_CODE_LENGTH + 1],
+            symbols: [0; LITERAL_LENGTH_CODES],
+        };
+        for length in lengths {
+            huffman.counts[*length as usize] += 1;
+        }
+        huffman.counts[0] = 0;
+        let mut left: i32 = 1;
+        for count in &huffman.counts[1..] {
+            left = (left << 1) - *count as i32;
+            if left < 0 {
+                return Err(ImageError::Corrupt);
+            }
+        }
+        let mut offsets = [0u16; MAX_CODE_LENGTH + 1];
+        for length in 1..MAX_CODE_LENGTH {
+            offsets[length + 1] = offsets[length] + huffman.counts[length];
+        }
+        for (symbol, length) in lengths.iter().enumerate() {
+            if *length != 0 {
+                huffman.symbols[offsets[*length as usize] as usize] = symbol as u16;
+                offsets[*length as usize] += 1;
+            }
+        }
+        Ok(huffman)
+    }
+
+    fn decode<I: Iterator<Item = u8>>(&self, reader: &mut BitReader<I>) -> Result<u16, ImageError> {
+        // Codes of each length follow on from the last code of the length before.
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for count in &self.counts[1..] {
+            code |= reader.bits(1)? as i32;
+            let count = *count as i32;
+            if code - first < count {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err(ImageError::Corrupt)
+    }
+}
+
+/// Where decompressed bytes go: the window, the checksum and the caller.
+struct Output<'w, F> {
+    window: &'w mut [u8],
+    total: usize,
+    adler: (u32, u32),
+    sink: F,
+}
+
+impl<F: FnMut(u8) -> Result<(), ImageError>> Output<'_, F> {
+    fn push(&mut self, byte: u8) -> Result<(), ImageError> {
+        self.window[self.total % WINDOW_SIZE] = byte;
+        self.total += 1;
+        self.adler.0 = (self.adler.0 + byte as u32) % 65521;
+        self.adler.1 = (self.adler.1 + self.adler.0) % 65521;
+        (self.sink)(byte)
+    }
+
+    fn copy(&mut self, distance: usize, length: usize) -> Result<(), ImageError> {
+        if distance > self.total || distance > WINDOW_SIZE {
+            return Err(ImageError::Corrupt);
+        }
+        for _ in 0..length {
+            self.push(self.window[(self.total - distance) % WINDOW_SIZE])?;
+        }
+        Ok(())
+    }
+}
+
+/// Decompresses the zlib stream in `input`, passing every byte to `sink`.
+/// `window` must hold at least `WINDOW_SIZE` bytes.
+pub(super) fn inflate(
+    input: impl Iterator<Item = u8>,
+    window: &mut [u8],
+    sink: impl FnMut(u8) -> Result<(), ImageError>,
+) -> Result<(), ImageError> {
+    let mut reader = BitReader {
+        bytes: input,
+        buffer: 0,
+        count: 0,
+    };
+    let (method, flags) = (reader.bits(8)?, reader.bits(8)?);
+    let preset_dictionary = flags & 0x20 != 0;
+    if method & 0x0F != 8 || method >> 4 > 7 || (method << 8 | flags) % 31 != 0 || preset_dictionary {
+        return Err(ImageError::Unsupported);
+    }
+    let mut output = Output {
+        window,
+        total: 0,
+        adler: (1, 0),
+        sink,
+    };
+    loop {
+        let last = reader.bits(1)? == 1;
+        match reader.bits(2)? {
+            0 => stored_block(&mut reader, &mut output)?,
+            1 => {
+                let mut lengths = [0u8; LITERAL_LENGTH_CODES + DISTANCE_CODES];
+                lengths[..144].fill(8);
+                lengths[144..256].fill(9);
+                lengths[256..280].fill(7);
+                lengths[280..LITERAL_LENGTH_CODES].fill(8);
+                lengths[LITERAL_LENGTH_CODES..].fill(5);
+                let literal_length = Huffman::new(&lengths[..LITERAL_LENGTH_CODES])?;
+                let distance = Huffman::new(&lengths[LITERAL_LENGTH_CODES..])?;
+                compressed_block(&mut reader, &mut output, &literal_length, &distance)?;
+            }
+            2 => {
+                let (literal_length, distance) = dynamic_codes(&mut reader)?;
+                compressed_block(&mut reader, &mut output, &literal_length, &distance)?;
+            }
+            _ => return Err(ImageError::Corrupt),
+        }
+        if last {
+            break;
+        }
+    }
+    reader.align();
+    let mut checksum = 0;
+    for _ in 0..4 {
+        checksum = checksum << 8 | reader.bits(8)?;
+    }
+    if checksum != output.adler.1 << 16 | output.adler.0 {
+        return Err(ImageError::Corrupt);
+    }
+    Ok(())
+}
+
+fn stored_block<I, F>(reader: &mut BitReader<I>, output: &mut Output<F>) -> Result<(), ImageError>
+where
+    I: Iterator<Item = u8>,
+    F: FnMut(u8) -> Result<(), ImageError>,
+{
+    reader.align();
+    let length = reader.bits(16)?;
+    if length != !reader.bits(16)? & 0xFFFF {
+        return Err(ImageError::Corrupt);
+    }
+    for _ in 0..length {
+        output.push(reader.bits(8)? as u8)?;
+    }
+    Ok(())
+}
+
+fn compressed_block<I, F>(
+    reader: &mut BitReader<I>,
+    output: &mut Output<F>,
+    literal_length: &Huffman,
+    distance: &Huffman,
+) -> Result<(), ImageError>
+where
+    I: Iterator<Item = u8>,
+    F: FnMut(u8) -> Result<(), ImageError>,
+{
+    loop {
+        let symbol = literal_length.decode(reader)?;
+        if symbol < END_OF_BLOCK {
+            output.push(symbol as u8)?;
+            continue;
+        }
+        if symbol == END_OF_BLOCK {
+            return Ok(());
+        }
+        let index = (symbol - END_OF_BLOCK - 1) as usize;
+        let (Some(base), Some(extra)) = (LENGTH_BASE.get(index), LENGTH_EXTRA_BITS.get(index)) else {
+            return Err(ImageError::Corrupt);
+        };
+        let length = *base as usize + reader.bits(*extra as u32)? as usize;
+        let index = distance.decode(reader)? as usize;
+        let (Some(base), Some(extra)) = (DISTANCE_BASE.get(index), DISTANCE_EXTRA_BITS.get(index)) else {
+            return Err(ImageError::Corrupt);
+        };
+        output.copy(*base as usize + reader.bits(*extra as u32)? as usize, length)?;
+    }
+}
+
+/// Reads the code length code of a dynamic block, then the two codes it describes.
+fn dynamic_codes<I: Iterator<Item = u8>>(reader: &mut BitReader<I>) -> Result<(Huffman, Huffman), ImageError> {
+    let literal_length_count = reader.bits(5)? as usize + 257;
+    let distance_count = reader.bits(5)? as usize + 1;
+    let code_length_count = reader.bits(4)? as usize + 4;
+    if literal_length_count > 286 || distance_count > DISTANCE_CODES {
+        return Err(ImageError::Corrupt);
+    }
+    let mut code_lengths = [0u8; 19];
+    for index in CODE_LENGTH_ORDER.iter().take(code_length_count) {
+        code_lengths[*index] = reader.bits(3)? as u8;
+    }
+    let code_length_code = Huffman::new(&code_lengths)?;
+
+    let mut lengths = [0u8; LITERAL_LENGTH_CODES + DISTANCE_CODES];
+    let total = literal_length_count + distance_count;
+    let mut index = 0;
+    while index < total {
+        let symbol = code_length_code.decode(reader)?;
+        let (value, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => {
+                let previous = *index.checked_sub(1).and_then(|i| lengths.get(i)).ok_or(ImageError::Corrupt)?;
+                (previous, 3 + reader.bits(2)? as usize)
+            }
+            17 => (0, 3 + reader.bits(3)? as usize),
+            _ => (0, 11 + reader.bits(7)? as usize),
+        };
+        if index + repeat > total {
+            return Err(ImageError::Corrupt);
+        }
+        lengths[index..index + repeat].fill(value);
+        index += repeat;
+    }
+    if lengths[END_OF_BLOCK as usize] == 0 {
+        return Err(ImageError::Corrupt);
+    }
+    Ok((
+        Huffman::new(&lengths[..literal_length_count])?,
+        Huffman::new(&lengths[literal_length_count..total])?,
+    ))
+}
@@ -0,0 +1,107 @@
+//! QOI, the "Quite OK Image" format: a byte stream of run, index, difference
+//! and literal operations, decoded in a single pass without a pixel buffer.
+
+use super::{Image, ImageError};
+use crate::Rgba;
+
+const MAGIC: &[u8; 4] = b"qoif";
+const HEADER_SIZE: usize = 14;
+
+const OP_RGB: u8 = 0xFE;
+const OP_RGBA: u8 = 0xFF;
+const OP_INDEX: u8 = 0x00;
+const OP_DIFF: u8 = 0x40;
+const OP_LUMA: u8 = 0x80;
+const TAG_MASK: u8 = 0xC0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Qoi<'a> {
+    width: usize,
+    height: usize,
+    /// The operations, followed by the end marker.
+    stream: &'a [u8],
+}
+
+impl<'a> Qoi<'a> {
+    pub fn parse(data: &'a [u8]) -> Result<Qoi<'a>, ImageError> {
+        if !data.starts_with(MAGIC) {
+            return Err(ImageError::InvalidMagic);
+        }
+        let header = data.get(..HEADER_SIZE).ok_or(ImageError::Truncated)?;
+        let width = u32::from_be_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        let height = u32::from_be_bytes([header[8], header[9], header[10], header[11]]) as usize;
+        if width == 0 || height == 0 || !matches!(header[12], 3 | 4) {
+            return Err(ImageError::Unsupported);
+        }
+        Ok(Qoi {
+            width,
+            height,
+            stream: &data[HEADER_SIZE..],
+        })
+    }
+}
+
+impl Image for Qoi<'_> {
+    fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    fn decode(&self, mut pixel: impl FnMut((usize, usize), Rgba)) -> Result<(), ImageError> {
+        let mut seen = [Rgba::new(0, 0, 0, 0); 64];
+        let mut color = Rgba::new(0, 0, 0, 255);
+        let mut position = 0;
+        let mut run = 0;
+        let byte = |position: usize| self.stream.get(position).copied().ok_or(ImageError::Truncated);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if run > 0 {
+                    run -= 1;
+                    pixel((x, y), color);
+                    continue;
+                }
+                let op = byte(position)?;
+                position += 1;
+                match op {
+                    OP_RGB => {
+                        color = Rgba::new(byte(position)?, byte(position + 1)?, byte(position + 2)?, color.a);
+                        position += 3;
+                    }
+                    OP_RGBA => {
+                        color = Rgba::new(
+                            byte(position)?,
+                            byte(position + 1)?,
+                            byte(position + 2)?,
+                            byte(position + 3)?,
+                        );
+                        position += 4;
+                    }
+                    _ => match op & TAG_MASK {
+                        OP_INDEX => color = seen[op as usize],
+                        OP_DIFF => {
+                            color.r = color.r.wrapping_add((op >> 4 & 0x03).wrapping_sub(2));
+                            color.g = color.g.wrapping_add((op >> 2 & 0x03).wrapping_sub(2));
+                            color.b = color.b.wrapping_add((op & 0x03).wrapping_sub(2));
+                        }
+                        OP_LUMA => {
+                            let green = (op & 0x3F).wrapping_sub(32);
+                            let next = byte(position)?;
+                            position += 1;
+                            color.r = color.r.wrapping_add(green.wrapping_add(next >> 4).wrapping_sub(8));
+                            color.g = color.g.wrapping_add(green);
+                            color.b = color.b.wrapping_add(green.wrapping_add(next & 0x0F).wrapping_sub(8));
+                        }
+                        // The remaining tag is a run, which includes this pixel.
+                        _ => run = op & 0x3F,
+                    },
+                }
+                seen[hash(color)] = color;
+                pixel((x, y), color);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn hash(color: Rgba) -> usize {
+    (color.r as usize * 3 + color.g as usize * 5 + color.b as usize * 7 + color.a as usize * 11) % 64
+}
@@ -0,0 +1,203 @@
+//! Truevision TARGA images: color-mapped, truecolor and grayscale, plain or
+//! run-length encoded, in any of the four corner orders.
+
+use super::{Image, ImageError, read_u16};
+use crate::Rgba;
+
+const HEADER_SIZE: usize = 18;
+
+const COLOR_MAPPED: u8 = 1;
+const TRUECOLOR: u8 = 2;
+const GRAYSCALE: u8 = 3;
+/// Added to the image type for the run-length encoded variants.
+const RLE: u8 = 8;
+
+const DESCRIPTOR_ALPHA_BITS: u8 = 0x0F;
+const DESCRIPTOR_RIGHT_TO_LEFT: u8 = 0x10;
+const DESCRIPTOR_TOP_TO_BOTTOM: u8 = 0x20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    ColorMapped,
+    Truecolor,
+    Grayscale,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Tga<'a> {
+    width: usize,
+    height: usize,
+    kind: Kind,
+    rle: bool,
+    bits_per_pixel: u8,
+    /// Whether the descriptor announces alpha bits; without them the extra
+    /// bits of 16 and 32 bit pixels are ignored.
+    has_alpha: bool,
+    right_to_left: bool,
+    top_to_bottom: bool,
+    color_map: &'a [u8],
+    color_map_first: usize,
+    color_map_bits: u8,
+    pixels: &'a [u8],
+}
+
+impl<'a> Tga<'a> {
+    /// TGA files have no signature, so this only checks that the header is
+    /// consistent.
+    pub fn parse(data: &'a [u8]) -> Result<Tga<'a>, ImageError> {
+        let header = data.get(..HEADER_SIZE).ok_or(ImageError::Truncated)?;
+        let id_length = header[0] as usize;
+        let has_color_map = header[1] == 1;
+        let (kind, rle) = match header[2] {
+            COLOR_MAPPED => (Kind::ColorMapped, false),
+            TRUECOLOR => (Kind::Truecolor, false),
+            GRAYSCALE => (Kind::Grayscale, false),
+            image_type if image_type == COLOR_MAPPED + RLE => (Kind::ColorMapped, true),
+            image_type if image_type == TRUECOLOR + RLE => (Kind::Truecolor, true),
+            image_type if image_type == GRAYSCALE + RLE => (Kind::Grayscale, true),
+            _ => return Err(ImageError::Unsupported),
+        };
+        let color_map_first = read_u16(data, 3)? as usize;
+        let color_map_length = read_u16(data, 5)? as usize;
+        let color_map_bits = header[7];
+        let width = read_u16(data, 12)? as usize;
+        let height = read_u16(data, 14)? as usize;
+        let bits_per_pixel = header[16];
+        let descriptor = header[17];
+
+        let pixel_size_supported = match kind {
+            Kind::ColorMapped => has_color_map && matches!(bits_per_pixel, 8 | 16),
+            Kind::Truecolor => matches!(bits_per_pixel, 15 | 16 | 24 | 32),
+            Kind::Grayscale => matches!(bits_per_pixel, 8 | 16),
+        };
+        if width == 0 || height == 0 || !pixel_size_supported {
+            return Err(ImageError::Unsupported);
+        }
+        if kind == Kind::ColorMapped && !matches!(color_map_bits, 15 | 16 | 24 | 32) {
+            return Err(ImageError::Unsupported);
+        }
+
+        let color_map_start = HEADER_SIZE + id_length;
+        let color_map_len = if has_color_map {
+            color_map_length * (color_map_bits as usize).div_ceil(8)
+        } else {
+            0
+        };
+        let color_map = data
+            .get(color_map_start..color_map_start + color_map_len)
+            .ok_or(ImageError::Truncated)?;
+        let tga = Tga {
+            width,
+            height,
+            kind,
+            rle,
+            bits_per_pixel,
+            has_alpha: descriptor & DESCRIPTOR_ALPHA_BITS != 0,
+            right_to_left: descriptor & DESCRIPTOR_RIGHT_TO_LEFT != 0,
+            top_to_bottom: descriptor & DESCRIPTOR_TOP_TO_BOTTOM != 0,
+            color_map,
+            color_map_first,
+            color_map_bits,
+            pixels: &data[color_map_start + color_map_len..],
+        };
+        if !rle && tga.pixels.len() < width * height * tga.pixel_size() {
+            return Err(ImageError::Truncated);
+        }
+        Ok(tga)
+    }
+
+    fn pixel_size(&self) -> usize {
+        (self.bits_per_pixel as usize).div_ceil(8)
+    }
+
+    /// The color of a pixel stored as `bytes`.
+    fn color(&self, bytes: &[u8]) -> Rgba {
+        match self.kind {
+            Kind::Truecolor => direct_color(bytes, self.bits_per_pixel, self.has_alpha),
+            Kind::Grayscale => {
+                let alpha = if self.has_alpha && bytes.len() > 1 { bytes[1] } else { 255 };
+                Rgba::new(bytes[0], bytes[0], bytes[0], alpha)
+            }
+            Kind::ColorMapped => {
+                let index = if bytes.len() > 1 {
+                    u16::from_le_bytes([bytes[0], bytes[1]]) as usize
+                } else {
+                    bytes[0] as usize
+                };
+                let entry_size = (self.color_map_bits as usize).div_ceil(8);
+                let start = index.wrapping_sub(self.color_map_first).saturating_mul(entry_size);
+                match self.color_map.get(start..start.saturating_add(entry_size)) {
+                    Some(entry) => direct_color(entry, self.color_map_bits, self.has_alpha),
+                    None => Rgba::new(0, 0, 0, 255),
+                }
+            }
+        }
+    }
+
+    /// Where the `index`th stored pixel goes in the image.
+    fn position(&self, index: usize) -> (usize, usize) {
+        let (column, row) = (index % self.width, index / self.width);
+        let x = if self.right_to_left { self.width - 1 - column } else { column };
+        let y = if self.top_to_bottom { row } else { self.height - 1 - row };
+        (x, y)
+    }
+}
+
+impl Image for Tga<'_> {
+    fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    fn decode(&self, mut pixel: impl FnMut((usize, usize), Rgba)) -> Result<(), ImageError> {
+        let size = self.pixel_size();
+        let count = self.width * self.height;
+        if !self.rle {
+            for (index, bytes) in self.pixels.chunks_exact(size).take(count).enumerate() {
+                pixel(self.position(index), self.color(bytes));
+            }
+            return Ok(());
+        }
+        // Packets hold up to 128 pixels and may run on into the next row.
+        let mut index = 0;
+        let mut position = 0;
+        while index < count {
+            let packet = *self.pixels.get(position).ok_or(ImageError::Truncated)?;
+            position += 1;
+            let len = (packet & 0x7F) as usize + 1;
+            if index + len > count {
+                return Err(ImageError::Corrupt);
+            }
+            if packet & 0x80 != 0 {
+                let bytes = self.pixels.get(position..position + size).ok_or(ImageError::Truncated)?;
+                position += size;
+                let color = self.color(bytes);
+                for _ in 0..len {
+                    pixel(self.position(index), color);
+                    index += 1;
+                }
+            } else {
+                let run = self.pixels.get(position..position + len * size).ok_or(ImageError::Truncated)?;
+                position += len * size;
+                for bytes in run.chunks_exact(size) {
+                    pixel(self.position(index), self.color(bytes));
+                    index += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A little-endian BGR(A) pixel of 15, 16, 24 or 32 bits.
+fn direct_color(bytes: &[u8], bits: u8, has_alpha: bool) -> Rgba {
+    match bits {
+        15 | 16 => {
+            let value = u16::from_le_bytes([bytes[0], bytes[1]]);
+            let channel = |shift: u16| ((value >> shift & 0x1F) * 255 / 31) as u8;
+            let alpha = if bits == 16 && has_alpha && value & 0x8000 == 0 { 0 } else { 255 };
+            Rgba::new(channel(10), channel(5), channel(0), alpha)
+        }
+        24 => Rgba::new(bytes[2], bytes[1], bytes[0], 255),
+        _ => Rgba::new(bytes[2], bytes[1], bytes[0], if has_alpha { bytes[3] } else { 255 }),
+    }
+}
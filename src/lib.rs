@@ -1,29 +1,103 @@
 #![no_std]
 #![allow(non_snake_case)]
 
-use core::{fmt, ptr::null_mut};
+use core::{
+    fmt,
+    ptr::{self, null_mut},
+};
 
 use common::*;
 
 use bootloader_api::info::{FrameBuffer, FrameBufferInfo, PixelFormat};
 use spin::Mutex;
 
-#[derive(Debug, Clone)]
+mod ansi;
+mod antialias;
+mod color;
+mod cursor;
+mod damage;
+mod draw;
+mod font;
+mod grid;
+mod image;
+mod math;
+mod path;
+mod pixel;
+mod screenshot;
+mod scrollback;
+mod search;
+mod tabs;
+
+use ansi::{Action, AnsiParser, Csi, palette_color, sgr_attribute};
+pub use color::{CompositeOp, Rgba};
+use cursor::{CursorState, inverted};
+pub use cursor::CursorStyle;
+use damage::DamageList;
+pub use draw::MAX_RADIUS;
+pub use font::{Font, FontError, Glyph, PsfFont};
+pub use grid::{Attributes, Cell};
+use grid::TextGrid;
+pub use image::{Bmp, Image, ImageError};
+#[cfg(feature = "png")]
+pub use image::Png;
+#[cfg(feature = "qoi")]
+pub use image::Qoi;
+#[cfg(feature = "tga")]
+pub use image::Tga;
+pub use path::{FillRule, Path};
+use pixel::PixelEncoder;
+pub use screenshot::{ByteSink, ScreenshotFormat};
+use scrollback::Scrollback;
+use search::Search;
+pub use search::{MAX_PATTERN_LEN, PatternTooLong, SearchMatch};
+use tabs::TabStops;
+pub use tabs::MAX_TAB_COLUMNS;
+
+/// Not `Clone`: it owns the framebuffer and the storage handed to it, and a
+/// copy would write to the same memory.
+#[derive(Debug)]
 pub struct DisplayTextManager {
     frame_info: FrameBufferInfo,
     buffer: *mut u8,
+    /// RAM copy of the framebuffer that drawing goes to when set; `flush` copies
+    /// the `damage`d parts of it to `buffer`.
+    back_buffer: *mut u8,
+    damage: DamageList,
+    pixel_encoder: PixelEncoder,
+    /// Whether blending mixes colors as linear light instead of sRGB values.
+    gamma_correct: bool,
+    font: Font,
+    /// The text on screen, when storage for it was given to `set_text_buffer`.
+    grid: TextGrid,
+    /// Rows scrolled off the top of the text buffer, when storage for them was
+    /// given to `set_scrollback_buffer`.
+    scrollback: Scrollback,
+    /// How many lines the view is scrolled back from the live text, at most
+    /// the number of lines in `scrollback`.
+    view_offset: usize,
+    search: Search,
     cursor: (usize, usize),
+    /// Set once a character was printed in the last column. Like on a VT100,
+    /// the cursor stays there and the next printed character wraps first, so
+    /// a carriage return or backspace after a full line stays on that line.
+    pending_wrap: bool,
+    cursor_state: CursorState,
+    saved_cursor: (usize, usize),
+    tab_stops: TabStops,
+    /// Whether backspace also erases the cell it moves back to.
+    destructive_backspace: bool,
+    /// Called for every bell character printed.
+    bell: Option<fn()>,
     foreground_color: (u8, u8, u8),
     background_color: (u8, u8, u8),
+    attributes: Attributes,
+    /// Colors restored by `ESC[0m`, `ESC[39m` and `ESC[49m`: the last ones set through the API.
+    default_foreground_color: (u8, u8, u8),
+    default_background_color: (u8, u8, u8),
+    ansi_parser: AnsiParser,
 }
 
-pub static DISPLAY_TEXT: Mutex<DisplayTextManager> = Mutex::new(DisplayTextManager {
-    frame_info: FrameBufferInfo { byte_len: 0, width: 0, height: 0, pixel_format: PixelFormat::U8, bytes_per_pixel: 0, stride: 0 },
-    buffer: null_mut(),
-    cursor: (0, 0),
-    foreground_color: (255,255,255),
-    background_color: (0,0,0)
-});
+pub static DISPLAY_TEXT: Mutex<DisplayTextManager> = Mutex::new(DisplayTextManager::empty());
 
 unsafe impl Sync for DisplayTextManager {}
 unsafe impl Send for DisplayTextManager {}
@@ -31,888 +105,856 @@ unsafe impl Send for DisplayTextManager {}
 #[derive(Debug)]
 pub struct IndexOutOfRange;
 
+#[derive(Debug)]
+pub struct BufferTooSmall;
+
+/// An area of the screen, in pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    pub const fn new(position: (usize, usize), size: (usize, usize)) -> Self {
+        Rect {
+            x: position.0,
+            y: position.1,
+            width: size.0,
+            height: size.1,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    pub fn area(&self) -> usize {
+        self.width * self.height
+    }
+
+    pub fn intersects(&self, other: &Rect) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.x < other.x + other.width
+            && other.x < self.x + self.width
+            && self.y < other.y + other.height
+            && other.y < self.y + self.height
+    }
+
+    /// The smallest rectangle containing both `self` and `other`.
+    pub fn union(&self, other: &Rect) -> Rect {
+        if self.is_empty() {
+            return *other;
+        }
+        if other.is_empty() {
+            return *self;
+        }
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        Rect {
+            x,
+            y,
+            width: (self.x + self.width).max(other.x + other.width) - x,
+            height: (self.y + self.height).max(other.y + other.height) - y,
+        }
+    }
+}
+
 pub fn init(framebuffer: &mut FrameBuffer) {
-    *DISPLAY_TEXT.lock() = DisplayTextManager {
-        frame_info: framebuffer.info(),
-        buffer: framebuffer.buffer_mut().as_ptr().cast_mut(),
-        cursor: (0, 0),
-        foreground_color: (255, 255, 255),
-        background_color: (0, 0, 0),
+    let frame_info = framebuffer.info();
+    *DISPLAY_TEXT.lock() = DisplayTextManager::from_raw(framebuffer.buffer_mut().as_mut_ptr(), frame_info);
+}
+
+/// Advances the cursor blinking of `DISPLAY_TEXT` by one step, for calling
+/// from a timer interrupt. If the interrupted code holds the lock, this step
+/// is skipped rather than waiting for a lock that is never released.
+pub fn tick() {
+    if let Some(mut display) = DISPLAY_TEXT.try_lock() {
+        display.tick();
     }
 }
 
 impl DisplayTextManager {
+    /// A manager without a framebuffer, drawing nothing until `init` replaces it.
+    const fn empty() -> Self {
+        DisplayTextManager {
+            frame_info: FrameBufferInfo {
+                byte_len: 0,
+                bytes_per_pixel: 0,
+                width: 0,
+                height: 0,
+                pixel_format: PixelFormat::U8,
+                stride: 0,
+            },
+            buffer: null_mut(),
+            back_buffer: null_mut(),
+            damage: DamageList::new(),
+            pixel_encoder: PixelEncoder::new(PixelFormat::U8, 0),
+            gamma_correct: false,
+            font: Font::Builtin,
+            grid: TextGrid::new(),
+            scrollback: Scrollback::new(),
+            view_offset: 0,
+            search: Search::new(),
+            cursor: (0, 0),
+            pending_wrap: false,
+            cursor_state: CursorState::new(),
+            saved_cursor: (0, 0),
+            tab_stops: TabStops::new(),
+            destructive_backspace: false,
+            bell: None,
+            foreground_color: (255, 255, 255),
+            background_color: (0, 0, 0),
+            attributes: Attributes::NONE,
+            default_foreground_color: (255, 255, 255),
+            default_background_color: (0, 0, 0),
+            ansi_parser: AnsiParser::new(),
+        }
+    }
+
+    /// Builds a manager drawing into `buffer`, laid out as `frame_info` describes.
+    /// This does not need a bootloader framebuffer, so the manager can also run
+    /// on plain memory, for example in host-side tests.
+    pub fn new(buffer: &'static mut [u8], frame_info: FrameBufferInfo) -> Result<Self, BufferTooSmall> {
+        if buffer.len() < frame_info.height * frame_info.stride * frame_info.bytes_per_pixel {
+            return Err(BufferTooSmall);
+        }
+        Ok(DisplayTextManager::from_raw(buffer.as_mut_ptr(), frame_info))
+    }
+
+    fn from_raw(buffer: *mut u8, frame_info: FrameBufferInfo) -> Self {
+        DisplayTextManager {
+            frame_info,
+            buffer,
+            pixel_encoder: PixelEncoder::new(frame_info.pixel_format, frame_info.bytes_per_pixel),
+            ..DisplayTextManager::empty()
+        }
+    }
+
+    pub fn frame_info(&self) -> FrameBufferInfo {
+        self.frame_info
+    }
+
+    /// The bytes currently on screen, `height * stride * bytes_per_pixel` of them.
+    /// With a back buffer this is what was last flushed.
+    pub fn framebuffer(&self) -> &[u8] {
+        let len = self.frame_info.height * self.frame_info.stride * self.pixel_encoder.bytes_per_pixel();
+        if len == 0 {
+            return &[];
+        }
+        unsafe { core::slice::from_raw_parts(self.buffer, len) }
+    }
+
+    /// Cursor position as (column, row) in text cells.
+    pub fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
     pub fn set_pixel(
         &mut self,
         position: (usize, usize),
         color_rgb: (u8, u8, u8),
     ) -> Result<(), IndexOutOfRange> {
+        self.without_cursor(|manager| {
+            if position.0 >= manager.frame_info.width || position.1 >= manager.frame_info.height {
+                return Err(IndexOutOfRange);
+            }
+            manager.set_pixel_uncheck(position, color_rgb);
+            manager.add_damage(Rect::new(position, (1, 1)));
+            Ok(())
+        })
+    }
+
+    /// Writes one pixel to the drawing target. Callers add the area they drew to the damage.
+    fn set_pixel_uncheck(&mut self, position: (usize, usize), color_rgb: (u8, u8, u8)) {
+        let pixel = self.pixel_encoder.encode(color_rgb);
+        unsafe {
+            self.pixel_encoder.write(self.target().add(self.pixel_offset(position)), pixel);
+        }
+    }
+
+    /// Reads a pixel back from the drawing target, converted to RGB, as it is
+    /// without the cursor over it.
+    pub fn get_pixel(&self, position: (usize, usize)) -> Result<(u8, u8, u8), IndexOutOfRange> {
         if position.0 >= self.frame_info.width || position.1 >= self.frame_info.height {
             return Err(IndexOutOfRange);
         }
-        self.set_pixel_uncheck(position, color_rgb);
+        let color = self.get_pixel_uncheck(position);
+        Ok(if self.cursor_covers(position, false) { inverted(color) } else { color })
+    }
+
+    fn get_pixel_uncheck(&self, position: (usize, usize)) -> (u8, u8, u8) {
+        let pixel = unsafe { self.pixel_encoder.read(self.target().add(self.pixel_offset(position))) };
+        self.pixel_encoder.decode(pixel)
+    }
+
+    fn pixel_offset(&self, position: (usize, usize)) -> usize {
+        (position.1 * self.frame_info.stride + position.0) * self.pixel_encoder.bytes_per_pixel()
+    }
+
+    /// Memory that drawing operations write to: the back buffer if there is one.
+    fn target(&self) -> *mut u8 {
+        if self.back_buffer.is_null() {
+            self.buffer
+        } else {
+            self.back_buffer
+        }
+    }
+
+    fn add_damage(&mut self, rect: Rect) {
+        self.damage.add(rect);
+    }
+
+    /// Areas changed since the last `flush` or `clear_damage`. Overlapping
+    /// areas are merged, so a present step never copies a pixel twice.
+    pub fn damage(&self) -> &[Rect] {
+        self.damage.rects()
+    }
+
+    pub fn clear_damage(&mut self) {
+        self.damage.clear();
+    }
+
+    /// Makes all drawing go through `back_buffer`, a RAM copy of the framebuffer
+    /// that is only copied to the screen by `flush`. It must hold at least
+    /// `height * stride * bytes_per_pixel` bytes; the current screen contents
+    /// are copied into it.
+    pub fn set_back_buffer(&mut self, back_buffer: &'static mut [u8]) -> Result<(), BufferTooSmall> {
+        let len = self.frame_info.height * self.frame_info.stride * self.pixel_encoder.bytes_per_pixel();
+        if back_buffer.len() < len {
+            return Err(BufferTooSmall);
+        }
+        self.flush();
+        if len > 0 {
+            unsafe {
+                ptr::copy_nonoverlapping(self.buffer, back_buffer.as_mut_ptr(), len);
+            }
+        }
+        self.back_buffer = back_buffer.as_mut_ptr();
+        self.cursor_flushed();
         Ok(())
     }
 
-    fn set_pixel_uncheck(&mut self, position: (usize, usize), color_rgb: (u8, u8, u8)) {
-        unsafe {
-            *self.buffer.add(
-                position.1 * self.frame_info.stride * self.frame_info.bytes_per_pixel
-                    + position.0 * self.frame_info.bytes_per_pixel,
-            ) = color_rgb.2;
-            *self.buffer.add(
-                position.1 * self.frame_info.stride * self.frame_info.bytes_per_pixel
-                    + position.0 * self.frame_info.bytes_per_pixel
-                    + 1,
-            ) = color_rgb.1;
-            *self.buffer.add(
-                position.1 * self.frame_info.stride * self.frame_info.bytes_per_pixel
-                    + position.0 * self.frame_info.bytes_per_pixel
-                    + 2,
-            ) = color_rgb.0;
+    /// Copies the damaged areas of the back buffer to the framebuffer and clears
+    /// the damage list.
+    pub fn flush(&mut self) {
+        if !self.back_buffer.is_null() {
+            let bytes_per_pixel = self.pixel_encoder.bytes_per_pixel();
+            for rect in self.damage.rects() {
+                for y in rect.y..rect.y + rect.height {
+                    let offset = self.pixel_offset((rect.x, y));
+                    unsafe {
+                        ptr::copy_nonoverlapping(
+                            self.back_buffer.add(offset),
+                            self.buffer.add(offset),
+                            rect.width * bytes_per_pixel,
+                        );
+                    }
+                }
+            }
         }
+        self.damage.clear();
+        self.cursor_flushed();
     }
 
     pub fn fill(&mut self, color_rgb: (u8, u8, u8)) {
-        for x in 0..self.frame_info.width {
-            for y in 0..self.frame_info.height {
-                self.set_pixel_uncheck((x, y), color_rgb);
+        self.fill_rect_uncheck((0, 0), (self.frame_info.width, self.frame_info.height), color_rgb);
+        self.cursor_state.drawn_at = None;
+        self.draw_cursor();
+    }
+
+    fn fill_rect_uncheck(&mut self, position: (usize, usize), size: (usize, usize), color_rgb: (u8, u8, u8)) {
+        let pixel = self.pixel_encoder.encode(color_rgb);
+        for y in position.1..position.1 + size.1 {
+            self.write_span_uncheck((position.0, y), size.0, pixel);
+        }
+        self.add_damage(Rect::new(position, size));
+    }
+
+    /// Writes `length` copies of an encoded pixel rightwards from `position`.
+    /// Callers add the area they drew to the damage.
+    fn write_span_uncheck(&mut self, position: (usize, usize), length: usize, pixel: u32) {
+        let bytes_per_pixel = self.pixel_encoder.bytes_per_pixel();
+        let row = unsafe { self.target().add(self.pixel_offset(position)) };
+        for x in 0..length {
+            unsafe {
+                self.pixel_encoder.write(row.add(x * bytes_per_pixel), pixel);
             }
         }
     }
 
+    /// Changes the font used for text drawn from now on. The cursor keeps its
+    /// cell position, clamped to the text grid of the new font.
+    ///
+    /// With a text buffer the screen is cleared and the stored text is drawn
+    /// again in the new font, as far as it fits.
+    pub fn set_font(&mut self, font: Font) {
+        self.erase_cursor();
+        self.font = font;
+        let (columns, rows) = self.text_size();
+        self.cursor.0 = self.cursor.0.min(columns.saturating_sub(1));
+        self.cursor.1 = self.cursor.1.min(rows.saturating_sub(1));
+        self.pending_wrap = false;
+        if self.grid.is_attached() {
+            let blank = self.blank_cell();
+            self.grid.resize((columns, rows), blank);
+            self.scrollback.reset(columns);
+            self.view_offset = 0;
+            self.search.current = None;
+            self.fill(self.default_background_color);
+            self.redraw_text();
+        }
+        self.draw_cursor();
+    }
+
+    pub fn font(&self) -> &Font {
+        &self.font
+    }
+
+    /// Number of text columns and rows that fit on screen with the current font.
+    /// With a text buffer, only as many rows as it can hold are used.
+    pub fn text_size(&self) -> (usize, usize) {
+        let (cell_width, cell_height) = self.font.cell_size();
+        let (columns, rows) = (self.frame_info.width / cell_width, self.frame_info.height / cell_height);
+        if self.grid.is_attached() && columns > 0 {
+            (columns, rows.min(self.grid.capacity() / columns))
+        } else {
+            (columns, rows)
+        }
+    }
+
+    /// Keeps the text on screen in `cells`, a grid of at least `columns * rows`
+    /// cells for the current font. Text is then drawn from the grid, which
+    /// `redraw_text` and font changes use to draw it again. The grid starts out
+    /// blank: text printed before is not part of it.
+    pub fn set_text_buffer(&mut self, cells: &'static mut [Cell]) -> Result<(), BufferTooSmall> {
+        let (columns, rows) = self.text_size();
+        if cells.len() < columns * rows {
+            return Err(BufferTooSmall);
+        }
+        let blank = self.blank_cell();
+        self.grid.attach(cells, (columns, rows), blank);
+        self.scrollback.reset(columns);
+        self.view_offset = 0;
+        self.search.current = None;
+        Ok(())
+    }
+
+    /// Keeps rows that scroll off the top of the text buffer in `cells`, as
+    /// many as fit in lines of the current `columns`. Once full, the oldest
+    /// rows are dropped. Changing the font forgets them.
+    pub fn set_scrollback_buffer(&mut self, cells: &'static mut [Cell]) -> Result<(), BufferTooSmall> {
+        let columns = self.text_size().0;
+        if columns == 0 || cells.len() < columns {
+            return Err(BufferTooSmall);
+        }
+        self.scrollback.attach(cells, columns);
+        self.scroll_to_bottom();
+        Ok(())
+    }
+
+    /// Number of rows kept in the scrollback buffer.
+    pub fn scrollback_len(&self) -> usize {
+        self.scrollback.len()
+    }
+
+    /// How many lines the view is scrolled back from the live text.
+    pub fn view_offset(&self) -> usize {
+        self.view_offset
+    }
+
+    /// Shows the text `offset` lines above the live text, as far as the
+    /// scrollback goes. Printing returns the view to the live text.
+    pub fn set_view_offset(&mut self, offset: usize) {
+        let offset = offset.min(self.scrollback.len());
+        if offset != self.view_offset {
+            self.view_offset = offset;
+            self.redraw_text();
+        }
+    }
+
+    pub fn scroll_view_up(&mut self, lines: usize) {
+        self.set_view_offset(self.view_offset.saturating_add(lines));
+    }
+
+    pub fn scroll_view_down(&mut self, lines: usize) {
+        self.set_view_offset(self.view_offset.saturating_sub(lines));
+    }
+
+    /// Scrolls the view up by a screenful of rows.
+    pub fn page_up(&mut self) {
+        self.scroll_view_up(self.text_size().1);
+    }
+
+    /// Scrolls the view down by a screenful of rows.
+    pub fn page_down(&mut self) {
+        self.scroll_view_down(self.text_size().1);
+    }
+
+    /// Returns the view to the live text.
+    pub fn scroll_to_bottom(&mut self) {
+        self.set_view_offset(0);
+    }
+
+    /// The cell shown at `(column, row)`, from the scrollback when the view is
+    /// scrolled back and from the text buffer otherwise.
+    fn visible_cell(&self, position: (usize, usize)) -> Option<Cell> {
+        self.text_line(self.visible_line(position.1))?.get(position.0).copied()
+    }
+
+    /// The line shown on `row`, counted as in `SearchMatch`.
+    fn visible_line(&self, row: usize) -> usize {
+        self.scrollback.len() - self.view_offset + row
+    }
+
+    /// The cell at `(column, row)`, if there is a text buffer.
+    pub fn cell(&self, position: (usize, usize)) -> Option<Cell> {
+        self.grid.get(position)
+    }
+
+    /// Replaces the cell at `(column, row)` and draws it.
+    pub fn set_cell(&mut self, position: (usize, usize), cell: Cell) -> Result<(), IndexOutOfRange> {
+        let (columns, rows) = self.text_size();
+        if position.0 >= columns || position.1 >= rows {
+            return Err(IndexOutOfRange);
+        }
+        self.scroll_to_bottom();
+        self.erase_cursor();
+        self.write_cell(position, cell);
+        self.draw_cursor();
+        Ok(())
+    }
+
+    /// Draws every cell of the text buffer again, or of the scrollback where
+    /// the view is scrolled back.
+    pub fn redraw_text(&mut self) {
+        self.erase_cursor();
+        let (columns, rows) = self.text_size();
+        for row in 0..rows {
+            for column in 0..columns {
+                if let Some(cell) = self.visible_cell((column, row)) {
+                    let cell = self.highlighted(self.visible_line(row), column, cell);
+                    self.draw_cell((column, row), cell);
+                }
+            }
+        }
+        self.draw_cursor();
+    }
+
+    /// Writes the text on screen to `out`, as `write_text_rect` does. When the
+    /// view is scrolled back, this is text from the scrollback.
+    pub fn write_text(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        self.write_text_rect((0, 0), self.text_size(), out)
+    }
+
+    /// Writes the text of `size` cells from `(column, row)` to `out`, one line
+    /// per row ended by `'\n'` and without trailing spaces. Only cells of the
+    /// text buffer are written, so nothing is without one.
+    pub fn write_text_rect(
+        &self,
+        position: (usize, usize),
+        size: (usize, usize),
+        out: &mut impl fmt::Write,
+    ) -> fmt::Result {
+        let (columns, rows) = self.text_size();
+        if !self.grid.is_attached() {
+            return Ok(());
+        }
+        let end = (position.0.saturating_add(size.0).min(columns), position.1.saturating_add(size.1).min(rows));
+        for row in position.1..end.1 {
+            let character = |column| self.visible_cell((column, row)).map_or(' ', |cell| cell.character);
+            let line_end = (position.0..end.0)
+                .rev()
+                .find(|column| character(*column) != ' ')
+                .map_or(position.0, |last| last + 1);
+            for column in position.0..line_end {
+                out.write_char(character(column))?;
+            }
+            out.write_char('\n')?;
+        }
+        Ok(())
+    }
+
+    /// What erased cells are set to: a space in the current colors.
+    fn blank_cell(&self) -> Cell {
+        Cell::new(' ', self.foreground_color, self.background_color, Attributes::NONE)
+    }
+
+    /// Adds a tab stop at `column`. Columns from `MAX_TAB_COLUMNS` on cannot
+    /// have one.
+    pub fn set_tab_stop(&mut self, column: usize) {
+        self.tab_stops.set(column);
+    }
+
+    pub fn clear_tab_stop(&mut self, column: usize) {
+        self.tab_stops.clear(column);
+    }
+
+    /// Removes every tab stop, so tabs move to the last column.
+    pub fn clear_tab_stops(&mut self) {
+        self.tab_stops.clear_all();
+    }
+
+    /// Puts back the default tab stops, every 8 columns.
+    pub fn reset_tab_stops(&mut self) {
+        self.tab_stops = TabStops::new();
+    }
+
+    /// Makes backspace erase the cell it moves back to, instead of only moving
+    /// the cursor.
+    pub fn set_destructive_backspace(&mut self, destructive: bool) {
+        self.destructive_backspace = destructive;
+    }
+
+    /// Registers `handler` to be called when a bell (`\x07`) is printed.
+    pub fn set_bell_handler(&mut self, handler: Option<fn()>) {
+        self.bell = handler;
+    }
+
+    /// Prints `text` at the cursor, interpreting ANSI escape sequences.
     pub fn print(&mut self, text: &str) {
+        self.scroll_to_bottom();
+        self.erase_cursor();
+        self.cursor_state.restart_blink();
         for c in text.chars() {
-            if c == '\n' {
-                self.cursor.1 += 1;
-                self.cursor.0 = 0;
-            } else {
-                self.write_char_uncheck(
-                    c,
-                    (self.cursor.0 * 10, self.cursor.1 * 10),
-                    self.foreground_color,
-                    self.background_color,
-                );
+            match self.ansi_parser.advance(c) {
+                Some(Action::Print(c)) => self.put_char(c),
+                Some(Action::Escape(c)) => self.execute_escape(c),
+                Some(Action::Csi(sequence)) => self.execute_csi(&sequence),
+                None => {}
+            }
+        }
+        self.draw_cursor();
+    }
+
+    fn put_char(&mut self, c: char) {
+        let (columns, rows) = self.text_size();
+        if columns == 0 || rows == 0 {
+            return;
+        }
+        if c == '\n' || c == '\x0b' {
+            self.new_line(rows);
+        } else if c.is_ascii_control() {
+            self.execute_control(c);
+        } else {
+            if self.pending_wrap || self.cursor.0 >= columns {
+                self.new_line(rows);
+            }
+            let cell = Cell::new(c, self.foreground_color, self.background_color, self.attributes);
+            self.write_cell(self.cursor, cell);
+            if self.cursor.0 + 1 < columns {
                 self.cursor.0 += 1;
+            } else {
+                self.pending_wrap = true;
+            }
+        }
+    }
+
+    /// Moves the cursor to the start of the next line, scrolling at the bottom.
+    fn new_line(&mut self, rows: usize) {
+        self.pending_wrap = false;
+        self.cursor.0 = 0;
+        self.cursor.1 += 1;
+        if self.cursor.1 >= rows {
+            self.scroll_up();
+            self.cursor.1 = rows - 1;
+        }
+    }
+
+    /// Carries out a C0 control character other than line feed. Those without
+    /// a meaning here are ignored rather than drawn.
+    fn execute_control(&mut self, c: char) {
+        let columns = self.text_size().0;
+        if matches!(c, '\t' | '\r' | '\x08' | '\x0c') {
+            self.pending_wrap = false;
+        }
+        match c {
+            '\t' => self.cursor.0 = self.tab_stops.next(self.cursor.0, columns),
+            '\r' => self.cursor.0 = 0,
+            '\x08' => {
+                self.cursor.0 = self.cursor.0.saturating_sub(1);
+                if self.destructive_backspace {
+                    self.erase_cells(self.cursor, self.cursor.0 + 1);
+                }
             }
-            if self.frame_info.width - self.cursor.0 * 10 < 10 {
-                self.cursor.0 = 0;
-                self.cursor.1 += 1;
+            '\x0c' => {
+                self.erase_rows(0, self.text_size().1);
+                self.cursor = (0, 0);
             }
-            if self.frame_info.height - self.cursor.1 * 10 < 10 {
-                self.cursor.1 = 0;
+            '\x07' => {
+                if let Some(bell) = self.bell {
+                    bell();
+                }
             }
+            _ => {}
         }
     }
 
-    fn write_char_uncheck(
-        &mut self,
-        c: char,
-        position: (usize, usize),
-        foreground: (u8, u8, u8),
-        background: (u8, u8, u8),
-    ) {
-        let B = background;
-        let W = foreground;
-        let pixels = match c {
-            ' ' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-            ],
-            'a' => [
-                [B, B, B, B, B, B, B, B],
-                [W, W, W, W, W, W, W, B],
-                [B, B, B, B, B, B, B, W],
-                [B, B, B, B, B, B, B, W],
-                [B, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, W, W],
-                [B, W, W, W, W, W, B, W],
-            ],
-            'b' => [
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, W, W, W, W, W, W, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, W, W, W, W, W, W, B],
-            ],
-            'c' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [B, W, W, W, W, W, W, W],
-            ],
-            'd' => [
-                [B, B, B, B, B, B, B, W],
-                [B, B, B, B, B, B, B, W],
-                [B, B, B, B, B, B, B, W],
-                [B, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [B, W, W, W, W, W, W, W],
-            ],
-            'e' => [
-                [B, B, B, B, B, B, B, B],
-                [B, W, W, W, W, W, W, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, W, W, W, W, W, W, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [B, W, W, W, W, W, W, W],
-            ],
-            'f' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, W, W, W, W, W],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [W, W, W, W, W, W, W, W],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-            ],
-            'g' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, W],
-                [B, W, W, W, W, W, W, W],
-                [B, B, B, B, B, B, B, W],
-                [B, B, B, B, B, B, B, W],
-                [W, W, W, W, W, W, W, B],
-            ],
-            'h' => [
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, W, W, W, W, W, W, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-            ],
-            'i' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, W, W, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [W, W, W, W, W, W, W, W],
-            ],
-            'j' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, W, W, W, B, B],
-                [B, B, B, B, B, W, B, B],
-                [W, B, B, B, B, W, B, B],
-                [W, B, B, B, B, W, B, B],
-                [B, W, W, W, W, B, B, B],
-            ],
-            'k' => [
-                [B, B, B, B, B, B, B, B],
-                [W, B, B, B, B, W, W, B],
-                [W, B, B, B, W, B, B, B],
-                [W, B, B, W, B, B, B, B],
-                [W, B, W, B, B, B, B, B],
-                [W, W, B, B, B, B, B, B],
-                [W, B, W, B, B, B, B, B],
-                [W, B, B, W, W, B, B, B],
-            ],
-            'l' => [
-                [B, B, B, B, B, B, B, B],
-                [W, W, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, B, W, W, W, W, W],
-            ],
-            'm' => [
-                [B, B, B, B, B, B, B, B],
-                [B, W, W, B, W, W, W, B],
-                [W, B, B, W, B, B, B, W],
-                [W, B, B, W, B, B, B, W],
-                [W, B, B, W, B, B, B, W],
-                [W, B, B, W, B, B, B, W],
-                [W, B, B, W, B, B, B, W],
-                [W, B, B, W, B, B, B, W],
-            ],
-            'n' => [
-                [B, B, B, B, B, B, B, B],
-                [W, B, W, W, W, W, W, B],
-                [W, W, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-            ],
-            'o' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, W, W, W, W, B, B],
-                [B, W, B, B, B, B, W, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [B, W, B, B, B, B, W, B],
-                [B, B, W, W, W, W, B, B],
-            ],
-            'p' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [W, W, W, W, W, W, W, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, W, W, W, W, W, W, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-            ],
-            'q' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [B, W, W, W, W, W, W, W],
-                [B, B, B, B, B, B, B, W],
-                [B, B, B, B, B, B, B, W],
-            ],
-            'r' => [
-                [B, B, B, B, B, B, B, B],
-                [W, B, W, W, W, W, W, W],
-                [W, W, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-            ],
-            's' => [
-                [B, B, B, B, B, B, B, B],
-                [B, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [B, W, W, W, W, W, W, B],
-                [B, B, B, B, B, B, B, W],
-                [B, B, B, B, B, B, B, W],
-                [W, W, W, W, W, W, W, B],
-            ],
-            't' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [W, W, W, W, W, W, W, W],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, B, W, W, W, W],
-            ],
-            'u' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [B, W, B, B, B, B, W, B],
-                [B, B, W, W, W, W, B, B],
-            ],
-            'v' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [B, W, B, B, B, B, W, B],
-                [B, B, W, B, B, W, B, B],
-                [B, B, B, W, W, B, B, B],
-                [B, B, B, W, B, B, B, B],
-            ],
-            'w' => [
-                [B, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [B, W, B, W, W, B, W, B],
-                [B, W, B, W, W, B, W, B],
-                [B, W, B, W, B, B, W, B],
-                [B, B, W, B, B, W, B, B],
-                [B, B, W, B, B, W, B, B],
-            ],
-            'x' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, W],
-                [B, W, B, B, B, B, W, B],
-                [B, B, W, B, B, W, B, B],
-                [B, B, B, W, W, B, B, B],
-                [B, W, W, B, B, W, W, B],
-                [W, B, B, B, B, B, B, W],
-            ],
-            'y' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, W, B, B, B, B, B, W],
-                [B, B, W, B, B, B, W, B],
-                [B, B, B, W, B, W, B, B],
-                [B, B, B, B, W, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [W, W, W, B, B, B, B, B],
-            ],
-            'z' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [W, W, W, W, W, W, W, W],
-                [B, B, B, B, B, B, W, B],
-                [B, B, B, B, W, W, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, W, W, B, B, B, B, B],
-                [W, W, W, W, W, W, W, W],
-            ],
-
-            'A' => [
-                [W, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-            ],
-            'B' => [
-                [W, W, W, W, W, W, W, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, W, W, W, W, W, W, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, W, W, W, W, W, W, B],
-            ],
-            'C' => [
-                [B, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [B, W, W, W, W, W, W, W],
-            ],
-            'D' => [
-                [W, W, W, W, W, W, B, B],
-                [W, B, B, B, B, B, W, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, W, B],
-                [W, W, W, W, W, W, B, B],
-            ],
-            'E' => [
-                [W, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, W, W, W, W, W, W, W],
-            ],
-            'F' => [
-                [W, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-            ],
-            'G' => [
-                [B, W, W, W, W, W, W, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, W, W, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [B, W, W, W, W, W, W, B],
-            ],
-            'H' => [
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-            ],
-            'I' => [
-                [W, W, W, W, W, W, W, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [W, W, W, W, W, W, W, B],
-            ],
-            'J' => [
-                [B, B, B, W, W, W, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, B, W, B, B],
-                [W, B, B, B, B, W, B, B],
-                [W, B, B, B, B, W, B, B],
-                [B, W, W, W, W, B, B, B],
-            ],
-            'K' => [
-                [W, B, B, B, W, W, B, B],
-                [W, B, B, W, B, B, B, B],
-                [W, B, W, B, B, B, B, B],
-                [W, W, B, B, B, B, B, B],
-                [W, B, W, B, B, B, B, B],
-                [W, B, B, W, B, B, B, B],
-                [W, B, B, B, W, B, B, B],
-                [W, B, B, B, B, W, B, B],
-            ],
-            'L' => [
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, W, W, W, W, W, W, W],
-            ],
-            'M' => [
-                [W, B, B, B, B, B, B, W],
-                [W, W, B, B, B, B, W, W],
-                [W, B, W, B, B, W, B, W],
-                [W, B, B, W, W, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-            ],
-            'N' => [
-                [W, B, B, B, B, B, B, W],
-                [W, W, B, B, B, B, B, W],
-                [W, B, W, B, B, B, B, W],
-                [W, B, B, W, B, B, B, W],
-                [W, B, B, B, W, B, B, W],
-                [W, B, B, B, B, W, B, W],
-                [W, B, B, B, B, B, W, W],
-                [W, B, B, B, B, B, B, W],
-            ],
-            'O' => [
-                [B, B, W, W, W, W, B, B],
-                [B, W, B, B, B, B, W, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [B, W, B, B, B, B, W, B],
-                [B, B, W, W, W, W, B, B],
-            ],
-            'P' => [
-                [W, W, W, W, W, W, W, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, W, W, W, W, W, W, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-            ],
-            'Q' => [
-                [B, B, W, W, W, W, B, B],
-                [B, W, B, B, B, B, W, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [B, W, B, B, B, B, B, W],
-                [B, B, W, W, W, W, W, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, B, B, W, W],
-            ],
-            'R' => [
-                [W, W, W, W, W, W, W, B],
-                [W, B, B, B, B, B, B, W],
-                [W, W, B, B, B, B, B, W],
-                [W, W, W, W, W, W, W, B],
-                [W, B, W, B, B, B, B, B],
-                [W, B, B, W, W, B, B, B],
-                [W, B, B, B, B, W, B, B],
-                [W, B, B, B, B, B, W, B],
-            ],
-            'S' => [
-                [B, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [B, W, W, W, W, W, W, B],
-                [B, B, B, B, B, B, B, W],
-                [B, B, B, B, B, B, B, W],
-                [B, B, B, B, B, B, B, W],
-                [W, W, W, W, W, W, W, B],
-            ],
-            'T' => [
-                [W, W, W, W, W, W, W, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-            ],
-            'U' => [
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [B, W, B, B, B, B, W, B],
-                [B, B, W, W, W, W, B, B],
-            ],
-            'V' => [
-                [W, B, B, B, B, B, W, B],
-                [W, B, B, B, B, B, W, B],
-                [W, B, B, B, B, B, W, B],
-                [B, W, B, B, B, W, B, B],
-                [B, W, B, B, B, W, B, B],
-                [B, B, W, B, W, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-            ],
-            'W' => [
-                [W, B, B, B, B, B, W, B],
-                [W, B, B, B, B, B, W, B],
-                [W, B, B, W, B, B, W, B],
-                [B, W, B, W, B, W, B, B],
-                [B, W, B, W, B, W, B, B],
-                [B, W, B, W, B, W, B, B],
-                [B, B, W, B, W, B, B, B],
-                [B, B, W, B, W, B, B, B],
-            ],
-            'X' => [
-                [W, B, B, B, B, B, B, W],
-                [B, W, B, B, B, B, W, B],
-                [B, B, W, B, B, W, B, B],
-                [B, B, B, W, W, B, B, B],
-                [B, B, B, W, W, B, B, B],
-                [B, B, W, B, B, W, B, B],
-                [B, W, B, B, B, B, W, B],
-                [W, B, B, B, B, B, B, W],
-            ],
-            'Y' => [
-                [W, B, B, B, B, B, W, B],
-                [B, W, B, B, B, W, B, B],
-                [B, B, W, B, W, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-            ],
-            'Z' => [
-                [W, W, W, W, W, W, W, W],
-                [B, B, B, B, B, B, W, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, W, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, W, B, B, B, B, B, B],
-                [W, W, W, W, W, W, W, W],
-            ],
-
-            '0' => [
-                [B, B, B, W, W, B, B, B],
-                [B, B, W, B, B, W, B, B],
-                [B, W, B, W, B, B, W, B],
-                [B, W, B, W, B, B, W, B],
-                [B, W, B, B, W, B, W, B],
-                [B, W, B, B, W, B, W, B],
-                [B, B, W, B, B, W, B, B],
-                [B, B, B, W, W, B, B, B],
-            ],
-            '1' => [
-                [B, W, W, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, W, W, W, W, W, B, B],
-            ],
-            '2' => [
-                [B, B, W, W, W, W, B, B],
-                [B, W, B, B, B, B, W, B],
-                [W, B, B, B, B, B, B, W],
-                [B, B, B, B, B, B, B, W],
-                [B, B, B, B, W, W, W, B],
-                [B, B, W, W, B, B, B, B],
-                [B, W, B, B, B, B, B, B],
-                [W, W, W, W, W, W, W, W],
-            ],
-            '3' => [
-                [B, W, W, W, W, W, B, B],
-                [W, B, B, B, B, B, W, B],
-                [B, B, B, B, B, B, B, W],
-                [B, B, W, W, W, W, W, B],
-                [B, B, B, B, B, B, B, W],
-                [B, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, W, B],
-                [B, W, W, W, W, W, B, B],
-            ],
-            '4' => [
-                [B, B, B, B, W, B, B, B],
-                [B, B, B, W, W, B, B, B],
-                [B, B, W, B, W, B, B, B],
-                [B, W, B, B, W, B, B, B],
-                [W, B, B, B, W, B, B, B],
-                [W, W, W, W, W, W, W, W],
-                [B, B, B, B, W, B, B, B],
-                [B, B, B, B, W, B, B, B],
-            ],
-            '5' => [
-                [W, W, W, W, W, W, W, W],
-                [W, B, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, W, W, W, W, W, B, B],
-                [B, B, B, B, B, B, W, B],
-                [B, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, W, B],
-                [B, W, W, W, W, W, B, B],
-            ],
-            '6' => [
-                [B, B, W, W, W, W, W, W],
-                [B, W, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-                [W, W, W, W, W, W, B, B],
-                [W, B, B, B, B, B, W, B],
-                [W, B, B, B, B, B, B, W],
-                [B, W, B, B, B, B, W, B],
-                [B, B, W, W, W, W, B, B],
-            ],
-            '7' => [
-                [W, W, W, W, W, W, W, W],
-                [B, B, B, B, B, B, W, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, W, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, W, B, B, B, B, B, B],
-                [W, B, B, B, B, B, B, B],
-            ],
-            '8' => [
-                [B, B, W, W, W, W, B, B],
-                [B, W, B, B, B, B, W, B],
-                [B, W, B, B, B, B, W, B],
-                [B, B, W, W, W, W, B, B],
-                [B, W, B, B, B, B, W, B],
-                [W, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, B, W],
-                [B, W, W, W, W, W, W, B],
-            ],
-            '9' => [
-                [B, B, W, W, W, W, B, B],
-                [B, W, B, B, B, B, W, B],
-                [W, B, B, B, B, B, B, W],
-                [B, W, B, B, B, B, B, W],
-                [B, B, W, W, W, W, W, W],
-                [B, B, B, B, B, B, B, W],
-                [W, B, B, B, B, B, W, B],
-                [B, W, W, W, W, W, B, B],
-            ],
-
-            '-' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, W, W, W, W, W, W, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-            ],
-            '_' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [W, W, W, W, W, W, W, W],
-            ],
-            '"' => [
-                [B, B, W, B, W, B, B, B],
-                [B, B, W, B, W, B, B, B],
-                [B, B, W, B, W, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-            ],
-            '{' => [
-                [B, B, B, B, W, W, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [W, W, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, B, W, W, B, B],
-            ],
-            '}' => [
-                [B, B, W, W, B, B, B, B],
-                [B, B, B, B, W, B, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, B, W, W, W],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, W, B, B, B],
-                [B, B, W, W, B, B, B, B],
-            ],
-            '[' => [
-                [B, B, W, W, W, W, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, W, W, W, B, B],
-            ],
-            ']' => [
-                [B, B, W, W, W, W, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, W, W, W, W, B, B],
-            ],
-            '(' => [
-                [B, B, B, B, W, W, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, B, W, W, B, B],
-            ],
-            ')' => [
-                [B, B, W, W, B, B, B, B],
-                [B, B, B, B, W, B, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, B, W, B, B],
-                [B, B, B, B, W, B, B, B],
-                [B, B, W, W, B, B, B, B],
-            ],
-            ',' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, W, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, W, B, B, B, B, B],
-            ],
-            '=' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, W, W, W, W, W, W, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, W, W, W, W, W, W, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-            ],
-            ':' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, W, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, W, B, B, B],
-            ],
-            '!' => [
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, W, B, B, B, B],
-            ],
-            '.' => [
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, B, B, B, B],
-                [B, B, B, B, W, B, B, B],
-            ],
-
-            _ => [
-                [W, W, W, W, W, W, W, W],
-                [W, W, W, W, W, W, W, W],
-                [W, W, W, W, W, W, W, W],
-                [W, W, W, W, W, W, W, W],
-                [W, W, W, W, W, W, W, W],
-                [W, W, W, W, W, W, W, W],
-                [W, W, W, W, W, W, W, W],
-                [W, W, W, W, W, W, W, W],
-            ],
-        };
-        for y in 0..8 {
-            for x in 0..8 {
-                self.set_pixel_uncheck((position.0 + x, position.1 + y), pixels[y][x]);
+    fn execute_escape(&mut self, c: char) {
+        match c {
+            '7' => self.saved_cursor = self.cursor,
+            '8' => self.restore_cursor(),
+            'H' => self.tab_stops.set(self.cursor.0),
+            _ => {}
+        }
+    }
+
+    /// Moves the cursor back to where `ESC 7` or `CSI s` saved it, clamped to
+    /// the text grid, which may have shrunk since.
+    fn restore_cursor(&mut self) {
+        let (columns, rows) = self.text_size();
+        self.pending_wrap = false;
+        self.cursor = (
+            self.saved_cursor.0.min(columns.saturating_sub(1)),
+            self.saved_cursor.1.min(rows.saturating_sub(1)),
+        );
+    }
+
+    fn execute_csi(&mut self, sequence: &Csi) {
+        if sequence.private {
+            // DECTCEM: `ESC[?25h` shows the cursor and `ESC[?25l` hides it.
+            if sequence.params() == [25] && matches!(sequence.final_byte, 'h' | 'l') {
+                self.cursor_state.visible = sequence.final_byte == 'h';
             }
-            self.set_pixel_uncheck((position.0 + 8, position.1 + y), background);
-            self.set_pixel_uncheck((position.0 + 9, position.1 + y), background);
+            return;
         }
-        for i in 0..2 {
-            for x in 0..10 {
-                self.set_pixel_uncheck((position.0 + x, position.1 + 8 + i), background);
+        let (columns, rows) = self.text_size();
+        let last_column = columns.saturating_sub(1);
+        let last_row = rows.saturating_sub(1);
+        let count = sequence.param_or(0, 1) as usize;
+        if matches!(sequence.final_byte, 'A'..='D' | 'H' | 'f') {
+            self.pending_wrap = false;
+        }
+        match sequence.final_byte {
+            'm' => self.select_graphic_rendition(sequence.params()),
+            'A' => self.cursor.1 = self.cursor.1.saturating_sub(count),
+            'B' => self.cursor.1 = (self.cursor.1 + count).min(last_row),
+            'C' => self.cursor.0 = (self.cursor.0 + count).min(last_column),
+            'D' => self.cursor.0 = self.cursor.0.saturating_sub(count),
+            'H' | 'f' => {
+                self.cursor.1 = (sequence.param_or(0, 1) as usize - 1).min(last_row);
+                self.cursor.0 = (sequence.param_or(1, 1) as usize - 1).min(last_column);
+            }
+            'J' => {
+                let (column, row) = self.cursor;
+                match sequence.params().first().copied().unwrap_or(0) {
+                    0 => {
+                        self.erase_cells((column, row), columns);
+                        self.erase_rows(row + 1, rows);
+                    }
+                    1 => {
+                        self.erase_rows(0, row);
+                        self.erase_cells((0, row), column + 1);
+                    }
+                    2 | 3 => self.erase_rows(0, rows),
+                    _ => {}
+                }
             }
+            'K' => {
+                let (column, row) = self.cursor;
+                match sequence.params().first().copied().unwrap_or(0) {
+                    0 => self.erase_cells((column, row), columns),
+                    1 => self.erase_cells((0, row), column + 1),
+                    2 => self.erase_cells((0, row), columns),
+                    _ => {}
+                }
+            }
+            'g' => match sequence.params().first().copied().unwrap_or(0) {
+                0 => self.tab_stops.clear(self.cursor.0),
+                3 => self.tab_stops.clear_all(),
+                _ => {}
+            },
+            's' => self.saved_cursor = self.cursor,
+            'u' => self.restore_cursor(),
+            _ => {}
         }
     }
+
+    fn select_graphic_rendition(&mut self, params: &[u16]) {
+        if params.is_empty() {
+            self.foreground_color = self.default_foreground_color;
+            self.background_color = self.default_background_color;
+            self.attributes = Attributes::NONE;
+            return;
+        }
+        let mut i = 0;
+        while i < params.len() {
+            match params[i] {
+                0 => {
+                    self.foreground_color = self.default_foreground_color;
+                    self.background_color = self.default_background_color;
+                    self.attributes = Attributes::NONE;
+                }
+                code @ (1 | 3 | 4 | 7 | 9) => self.attributes = self.attributes.union(sgr_attribute(code)),
+                code @ (22 | 23 | 24 | 27 | 29) => self.attributes = self.attributes.difference(sgr_attribute(code)),
+                code @ 30..=37 => self.foreground_color = palette_color((code - 30) as u8),
+                code @ 40..=47 => self.background_color = palette_color((code - 40) as u8),
+                code @ 90..=97 => self.foreground_color = palette_color((code - 90 + 8) as u8),
+                code @ 100..=107 => self.background_color = palette_color((code - 100 + 8) as u8),
+                39 => self.foreground_color = self.default_foreground_color,
+                49 => self.background_color = self.default_background_color,
+                code @ (38 | 48) => {
+                    let color = match params.get(i + 1) {
+                        Some(5) => {
+                            let index = params.get(i + 2).copied();
+                            i += 2;
+                            index.map(|index| palette_color(index.min(255) as u8))
+                        }
+                        Some(2) => {
+                            let channel = |offset: usize| params.get(i + offset).map(|value| (*value).min(255) as u8);
+                            let color = match (channel(2), channel(3), channel(4)) {
+                                (Some(red), Some(green), Some(blue)) => Some((red, green, blue)),
+                                _ => None,
+                            };
+                            i += 4;
+                            color
+                        }
+                        _ => None,
+                    };
+                    if let Some(color) = color {
+                        if code == 38 {
+                            self.foreground_color = color;
+                        } else {
+                            self.background_color = color;
+                        }
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+
+    /// Clears row `start.1` from column `start.0` up to (excluding) column `end`
+    /// with the background color.
+    fn erase_cells(&mut self, start: (usize, usize), end: usize) {
+        let (cell_width, cell_height) = self.font.cell_size();
+        let end = end.min(self.text_size().0);
+        if start.0 >= end {
+            return;
+        }
+        let blank = self.blank_cell();
+        self.grid.clear(start.1, start.0, end, blank);
+        self.fill_rect_uncheck(
+            (start.0 * cell_width, start.1 * cell_height),
+            ((end - start.0) * cell_width, cell_height),
+            self.background_color,
+        );
+    }
+
+    /// Clears the text rows from `start` up to (excluding) `end` with the background color.
+    fn erase_rows(&mut self, start: usize, end: usize) {
+        let cell_height = self.font.cell_size().1;
+        let end = end.min(self.text_size().1);
+        if start >= end {
+            return;
+        }
+        let blank = self.blank_cell();
+        for row in start..end {
+            self.grid.clear(row, 0, usize::MAX, blank);
+        }
+        self.fill_rect_uncheck(
+            (0, start * cell_height),
+            (self.frame_info.width, (end - start) * cell_height),
+            self.background_color,
+        );
+    }
+
+    /// Moves every text row up by one and clears the freed bottom row.
+    /// Pixel rows are copied one at a time so the padding past `width` in each
+    /// `stride` is never touched.
+    fn scroll_up(&mut self) {
+        let cell_height = self.font.cell_size().1;
+        let rows = self.text_size().1;
+        if rows == 0 {
+            return;
+        }
+        if let Some(line) = self.grid.row(0) {
+            let history = self.scrollback.len();
+            self.scrollback.push(line);
+            if self.scrollback.len() == history {
+                // The oldest line was dropped, so the others moved up by one.
+                self.search.current = self.search.current.and_then(|current| {
+                    Some(SearchMatch {
+                        line: current.line.checked_sub(1)?,
+                        ..current
+                    })
+                });
+            }
+        }
+        let blank = self.blank_cell();
+        self.grid.scroll_up(blank);
+        let row_len = self.frame_info.width * self.pixel_encoder.bytes_per_pixel();
+        let target = self.target();
+        for y in 0..(rows - 1) * cell_height {
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    target.add(self.pixel_offset((0, y + cell_height))),
+                    target.add(self.pixel_offset((0, y))),
+                    row_len,
+                );
+            }
+        }
+        self.add_damage(Rect::new((0, 0), (self.frame_info.width, (rows - 1) * cell_height)));
+        self.fill_rect_uncheck(
+            (0, (rows - 1) * cell_height),
+            (self.frame_info.width, cell_height),
+            self.background_color,
+        );
+    }
+
+    /// Stores `cell` at `(column, row)` in the text buffer, if any, and draws it.
+    /// While searching, the cells around it are drawn again too, as they may
+    /// have started or stopped matching.
+    fn write_cell(&mut self, position: (usize, usize), cell: Cell) {
+        self.grid.set(position, cell);
+        if !self.search.is_active() || !self.grid.is_attached() {
+            self.draw_cell(position, cell);
+            return;
+        }
+        let len = self.search.len();
+        let columns = self.text_size().0;
+        for column in (position.0 + 1).saturating_sub(len)..(position.0 + len).min(columns) {
+            if let Some(cell) = self.visible_cell((column, position.1)) {
+                let cell = self.highlighted(self.visible_line(position.1), column, cell);
+                self.draw_cell((column, position.1), cell);
+            }
+        }
+    }
+
+    /// Draws `cell` at `(column, row)`, which must be within `text_size`.
+    fn draw_cell(&mut self, position: (usize, usize), cell: Cell) {
+        if self.cursor_state.drawn_at == Some(position) {
+            // The cell is drawn over entirely, cursor included.
+            self.cursor_state.drawn_at = None;
+        }
+        let glyph = self.font.glyph(cell.character).unwrap_or_else(|| self.font.fallback_glyph());
+        let (cell_width, cell_height) = self.font.cell_size();
+        let (foreground, background) = cell.colors();
+        let has = |attribute| cell.attributes.contains(attribute);
+        let glyph_height = glyph.size().1.min(cell_height);
+        let position = (position.0 * cell_width, position.1 * cell_height);
+        for y in 0..cell_height {
+            // Italic glyphs lean over by a pixel for every four rows above the
+            // bottom, rounded to the nearest pixel.
+            let shift = if has(Attributes::ITALIC) { (glyph_height.saturating_sub(y + 1) + 2) / 4 } else { 0 };
+            let rule = (has(Attributes::UNDERLINE) && y + 1 == glyph_height)
+                || (has(Attributes::STRIKETHROUGH) && y == glyph_height / 2);
+            for x in 0..cell_width {
+                let set = |x: Option<usize>| x.is_some_and(|x| glyph.pixel(x, y));
+                let source = x.checked_sub(shift);
+                let lit = rule
+                    || set(source)
+                    || (has(Attributes::BOLD) && set(source.and_then(|x| x.checked_sub(1))));
+                let color = if lit { foreground } else { background };
+                self.set_pixel_uncheck((position.0 + x, position.1 + y), color);
+            }
+        }
+        self.add_damage(Rect::new(position, (cell_width, cell_height)));
+    }
 }
 
 impl fmt::Write for DisplayTextManager {
@@ -924,10 +966,12 @@ impl fmt::Write for DisplayTextManager {
 
 impl display_text_interface::DisplayText for DisplayTextManager {
     fn set_foreground_color(&mut self, color: (u8, u8, u8)) {
-        self.foreground_color = color
+        self.foreground_color = color;
+        self.default_foreground_color = color;
     }
     fn set_background_color(&mut self, color: (u8, u8, u8)) {
-        self.background_color = color
+        self.background_color = color;
+        self.default_background_color = color;
     }
     fn fill(&mut self, color: (u8, u8, u8)) {
         self.fill(color);
@@ -936,19 +980,6 @@ impl display_text_interface::DisplayText for DisplayTextManager {
 
 impl Default for DisplayTextManager {
     fn default() -> Self {
-        DisplayTextManager {
-            frame_info: FrameBufferInfo {
-                byte_len: 0,
-                bytes_per_pixel: 0,
-                width: 0,
-                height: 0,
-                pixel_format: PixelFormat::U8,
-                stride: 0,
-            },
-            buffer: null_mut(),
-            cursor: (0, 0),
-            foreground_color: (255, 255, 255),
-            background_color: (0, 0, 0)
-        }
+        DisplayTextManager::empty()
     }
 }
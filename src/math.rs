@@ -0,0 +1,46 @@
+//! The few floating point functions `core` does not provide.
+
+pub(crate) fn floor(x: f32) -> f32 {
+    let truncated = x as i64 as f32;
+    if truncated > x { truncated - 1.0 } else { truncated }
+}
+
+pub(crate) fn ceil(x: f32) -> f32 {
+    -floor(-x)
+}
+
+pub(crate) fn round(x: f32) -> f32 {
+    floor(x + 0.5)
+}
+
+pub(crate) fn fract(x: f32) -> f32 {
+    x - floor(x)
+}
+
+pub(crate) fn sqrt(x: f32) -> f32 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    // Halving the exponent gives a first guess within a few percent.
+    let mut root = f32::from_bits((x.to_bits() >> 1) + 0x1fbd_1df5);
+    for _ in 0..3 {
+        root = 0.5 * (root + x / root);
+    }
+    root
+}
+
+/// Sine and cosine of an angle in degrees.
+pub(crate) fn sin_cos(degrees: f32) -> (f32, f32) {
+    let turns = degrees / 90.0;
+    let quadrant = floor(turns + 0.5);
+    let radians = (turns - quadrant) * core::f32::consts::FRAC_PI_2;
+    let square = radians * radians;
+    let sin = radians * (1.0 - square / 6.0 * (1.0 - square / 20.0 * (1.0 - square / 42.0)));
+    let cos = 1.0 - square / 2.0 * (1.0 - square / 12.0 * (1.0 - square / 30.0));
+    match (quadrant as i64).rem_euclid(4) {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
@@ -0,0 +1,252 @@
+//! Filled polygons and paths. Paths are built from lines and Bézier curves,
+//! flattened into polygons as they are added, and filled scanline by scanline.
+
+use crate::math::{ceil, floor, sqrt};
+use crate::{BufferTooSmall, DisplayTextManager};
+
+/// Separates contours in the point storage of a `Path`.
+const CONTOUR_BREAK: (f32, f32) = (f32::NAN, f32::NAN);
+
+/// Upper bound on the segments a single curve is flattened into.
+const MAX_CURVE_SEGMENTS: usize = 256;
+
+/// Decides which parts of a self-intersecting or multi-contour shape are inside.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// Inside where a ray to infinity crosses the outline an odd number of times.
+    EvenOdd,
+    /// Inside where the outline winds around the point at least once.
+    NonZero,
+}
+
+impl FillRule {
+    fn is_inside(self, winding: i32) -> bool {
+        match self {
+            FillRule::EvenOdd => winding % 2 != 0,
+            FillRule::NonZero => winding != 0,
+        }
+    }
+}
+
+/// A shape made of one or more contours, stored as points in caller-supplied
+/// storage. Contours are closed implicitly when filled; each contour after the
+/// first takes one extra point of storage.
+#[derive(Debug)]
+pub struct Path<'a> {
+    points: &'a mut [(f32, f32)],
+    len: usize,
+    /// Maximum distance allowed between a curve and the lines replacing it.
+    tolerance: f32,
+    current: (f32, f32),
+    contour_start: (f32, f32),
+    /// Whether `current` is the last point of the stored contour.
+    open: bool,
+}
+
+impl<'a> Path<'a> {
+    /// Starts an empty path. `tolerance` is in pixels; smaller values flatten
+    /// curves into more segments.
+    pub fn new(storage: &'a mut [(f32, f32)], tolerance: f32) -> Self {
+        Path {
+            points: storage,
+            len: 0,
+            tolerance: if tolerance > 0.01 { tolerance } else { 0.01 },
+            current: (0.0, 0.0),
+            contour_start: (0.0, 0.0),
+            open: false,
+        }
+    }
+
+    /// Starts a new contour at `point`.
+    pub fn move_to(&mut self, point: (f32, f32)) -> Result<(), BufferTooSmall> {
+        if self.len > 0 {
+            self.push(CONTOUR_BREAK)?;
+        }
+        self.push(point)?;
+        self.current = point;
+        self.contour_start = point;
+        self.open = true;
+        Ok(())
+    }
+
+    pub fn line_to(&mut self, point: (f32, f32)) -> Result<(), BufferTooSmall> {
+        if !self.open {
+            self.move_to(self.current)?;
+        }
+        self.push(point)?;
+        self.current = point;
+        Ok(())
+    }
+
+    /// Adds a quadratic Bézier curve from the current point to `end`.
+    pub fn quad_to(&mut self, control: (f32, f32), end: (f32, f32)) -> Result<(), BufferTooSmall> {
+        let start = self.current;
+        let deviation = second_difference(start, control, end);
+        let segments = self.segments(deviation, 0.25);
+        for i in 1..=segments {
+            let t = i as f32 / segments as f32;
+            let u = 1.0 - t;
+            self.line_to((
+                u * u * start.0 + 2.0 * u * t * control.0 + t * t * end.0,
+                u * u * start.1 + 2.0 * u * t * control.1 + t * t * end.1,
+            ))?;
+        }
+        Ok(())
+    }
+
+    /// Adds a cubic Bézier curve from the current point to `end`.
+    pub fn cubic_to(
+        &mut self,
+        control1: (f32, f32),
+        control2: (f32, f32),
+        end: (f32, f32),
+    ) -> Result<(), BufferTooSmall> {
+        let start = self.current;
+        let deviation = second_difference(start, control1, control2).max(second_difference(control1, control2, end));
+        let segments = self.segments(deviation, 0.75);
+        for i in 1..=segments {
+            let t = i as f32 / segments as f32;
+            let u = 1.0 - t;
+            let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+            self.line_to((
+                a * start.0 + b * control1.0 + c * control2.0 + d * end.0,
+                a * start.1 + b * control1.1 + c * control2.1 + d * end.1,
+            ))?;
+        }
+        Ok(())
+    }
+
+    /// Ends the current contour. The next segment starts a new contour at the
+    /// point this one started from.
+    pub fn close(&mut self) {
+        self.current = self.contour_start;
+        self.open = false;
+    }
+
+    /// The flattened points, with contours separated by a `(NaN, NaN)` point.
+    pub fn points(&self) -> &[(f32, f32)] {
+        &self.points[..self.len]
+    }
+
+    fn push(&mut self, point: (f32, f32)) -> Result<(), BufferTooSmall> {
+        let slot = self.points.get_mut(self.len).ok_or(BufferTooSmall)?;
+        *slot = point;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Segment count that keeps a curve within `tolerance` of its chords, by
+    /// Wang's formula; `factor` is `degree * (degree - 1) / 8`.
+    fn segments(&self, deviation: f32, factor: f32) -> usize {
+        let segments = ceil(sqrt(factor * deviation / self.tolerance));
+        if segments.is_nan() || segments < 1.0 {
+            1
+        } else {
+            (segments as usize).min(MAX_CURVE_SEGMENTS)
+        }
+    }
+}
+
+/// Length of `a - 2b + c`, how far a curve bends at `b`.
+fn second_difference(a: (f32, f32), b: (f32, f32), c: (f32, f32)) -> f32 {
+    let (x, y) = (a.0 - 2.0 * b.0 + c.0, a.1 - 2.0 * b.1 + c.1);
+    sqrt(x * x + y * y)
+}
+
+impl DisplayTextManager {
+    /// Fills the polygon through `points`, which is closed implicitly. A pixel
+    /// is filled when its center is inside according to `rule`.
+    pub fn fill_polygon(&mut self, points: &[(f32, f32)], rule: FillRule, color_rgb: (u8, u8, u8)) {
+        self.without_cursor(|manager| {
+            let pixel = manager.pixel_encoder.encode(color_rgb);
+            let Some((top_left, bottom_right)) = bounds(points) else {
+                return;
+            };
+            let top = floor(top_left.1).max(0.0) as isize;
+            let bottom = ceil(bottom_right.1).min(manager.frame_info.height as f32) as isize;
+            for y in top..bottom {
+                let scanline = y as f32 + 0.5;
+                let mut winding = 0;
+                let mut span_start = f32::NEG_INFINITY;
+                let mut last_crossing = f32::NEG_INFINITY;
+                // Visit the crossings left to right without sorting them into a
+                // buffer: each pass looks for the nearest one past the previous.
+                loop {
+                    let mut next = f32::INFINITY;
+                    for (x, _) in crossings(points, scanline) {
+                        if x > last_crossing && x < next {
+                            next = x;
+                        }
+                    }
+                    if next == f32::INFINITY {
+                        break;
+                    }
+                    let was_inside = rule.is_inside(winding);
+                    winding += crossings(points, scanline)
+                        .filter(|(x, _)| *x == next)
+                        .map(|(_, direction)| direction)
+                        .sum::<i32>();
+                    match (was_inside, rule.is_inside(winding)) {
+                        (false, true) => span_start = next,
+                        (true, false) => {
+                            let start = ceil(span_start - 0.5) as isize;
+                            let end = ceil(next - 0.5) as isize - 1;
+                            manager.span(y, start, end, pixel);
+                        }
+                        _ => {}
+                    }
+                    last_crossing = next;
+                }
+            }
+            let left = floor(top_left.0) as isize;
+            let right = ceil(bottom_right.0) as isize;
+            manager.add_clipped_damage(
+                (left, top),
+                (right.saturating_sub(left).max(0) as usize, bottom.saturating_sub(top).max(0) as usize),
+            );
+        })
+    }
+
+    /// Fills every contour of `path` together, so holes follow from `rule`.
+    pub fn fill_path(&mut self, path: &Path, rule: FillRule, color_rgb: (u8, u8, u8)) {
+        self.fill_polygon(path.points(), rule, color_rgb);
+    }
+}
+
+/// The smallest rectangle around the points, ignoring contour breaks.
+fn bounds(points: &[(f32, f32)]) -> Option<((f32, f32), (f32, f32))> {
+    points
+        .iter()
+        .filter(|point| !point.0.is_nan() && !point.1.is_nan())
+        .fold(None, |bounds, point| match bounds {
+            None => Some((*point, *point)),
+            Some((min, max)) => Some((
+                (min.0.min(point.0), min.1.min(point.1)),
+                (max.0.max(point.0), max.1.max(point.1)),
+            )),
+        })
+}
+
+/// Where the outline crosses the horizontal line at `y`, with `1` for edges
+/// going down and `-1` for edges going up. Edges include their upper end only,
+/// so a vertex shared by two edges is not counted twice.
+fn crossings(points: &[(f32, f32)], y: f32) -> impl Iterator<Item = (f32, i32)> + '_ {
+    edges(points).filter_map(move |(from, to)| {
+        let (upper, lower, direction) = if from.1 < to.1 { (from, to, 1) } else { (to, from, -1) };
+        if y < upper.1 || y >= lower.1 {
+            return None;
+        }
+        Some((upper.0 + (y - upper.1) * (lower.0 - upper.0) / (lower.1 - upper.1), direction))
+    })
+}
+
+/// The edges of every contour, including the one closing it.
+fn edges(points: &[(f32, f32)]) -> impl Iterator<Item = ((f32, f32), (f32, f32))> + '_ {
+    points.split(|point| point.0.is_nan()).flat_map(|contour| {
+        let closing = match contour {
+            [first, .., last] => Some((*last, *first)),
+            _ => None,
+        };
+        contour.windows(2).map(|pair| (pair[0], pair[1])).chain(closing)
+    })
+}
@@ -0,0 +1,200 @@
+//! Conversion from RGB colors to the byte layout the framebuffer expects.
+//!
+//! The layout is resolved once from the `FrameBufferInfo` given to `init`, so
+//! drawing a pixel is only a few shifts and a short copy.
+
+use core::ptr;
+
+use common::bootloader_api::info::PixelFormat;
+
+/// Where one color channel lives inside a little-endian pixel value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Channel {
+    shift: u8,
+    bits: u8,
+}
+
+impl Channel {
+    const fn new(shift: u8, bits: u8) -> Self {
+        Channel { shift, bits }
+    }
+
+    const fn encode(self, value: u8) -> u32 {
+        if self.bits == 0 || self.shift >= 32 {
+            return 0;
+        }
+        ((value >> (8 - self.bits)) as u32) << self.shift
+    }
+
+    const fn decode(self, pixel: u32) -> u8 {
+        if self.bits == 0 || self.shift >= 32 {
+            return 0;
+        }
+        let max = (1u32 << self.bits) - 1;
+        ((pixel >> self.shift & max) * 255 / max) as u8
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Layout {
+    Color {
+        red: Channel,
+        green: Channel,
+        blue: Channel,
+    },
+    Gray {
+        bits: u8,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PixelEncoder {
+    layout: Layout,
+    bytes_per_pixel: usize,
+}
+
+impl PixelEncoder {
+    pub(crate) const fn new(pixel_format: PixelFormat, bytes_per_pixel: usize) -> Self {
+        let total_bits = if bytes_per_pixel > 4 { 32 } else { bytes_per_pixel as u8 * 8 };
+        let layout = match pixel_format {
+            PixelFormat::U8 => Layout::Gray { bits: total_bits },
+            PixelFormat::Rgb => match bytes_per_pixel {
+                0 | 1 => Layout::Color {
+                    red: Channel::new(0, 3),
+                    green: Channel::new(3, 3),
+                    blue: Channel::new(6, 2),
+                },
+                2 => Layout::Color {
+                    red: Channel::new(0, 5),
+                    green: Channel::new(5, 6),
+                    blue: Channel::new(11, 5),
+                },
+                _ => Layout::Color {
+                    red: Channel::new(0, 8),
+                    green: Channel::new(8, 8),
+                    blue: Channel::new(16, 8),
+                },
+            },
+            PixelFormat::Unknown {
+                red_position,
+                green_position,
+                blue_position,
+            } => Self::from_positions(red_position, green_position, blue_position, total_bits),
+            // `Bgr`, and the layout most firmware uses for anything newer.
+            _ => match bytes_per_pixel {
+                0 | 1 => Layout::Color {
+                    red: Channel::new(5, 3),
+                    green: Channel::new(2, 3),
+                    blue: Channel::new(0, 2),
+                },
+                2 => Layout::Color {
+                    red: Channel::new(11, 5),
+                    green: Channel::new(5, 6),
+                    blue: Channel::new(0, 5),
+                },
+                _ => Layout::Color {
+                    red: Channel::new(16, 8),
+                    green: Channel::new(8, 8),
+                    blue: Channel::new(0, 8),
+                },
+            },
+        };
+        PixelEncoder {
+            layout,
+            bytes_per_pixel,
+        }
+    }
+
+    /// Builds a layout from the bit offsets reported for `PixelFormat::Unknown`.
+    /// Each channel is as wide as the gap up to the next channel (at most 8 bits).
+    const fn from_positions(red: u8, green: u8, blue: u8, total_bits: u8) -> Layout {
+        const fn width(position: u8, others: [u8; 2], total_bits: u8) -> u8 {
+            let mut limit = total_bits;
+            let mut i = 0;
+            while i < others.len() {
+                if others[i] > position && others[i] < limit {
+                    limit = others[i];
+                }
+                i += 1;
+            }
+            if position >= limit {
+                0
+            } else if limit - position > 8 {
+                8
+            } else {
+                limit - position
+            }
+        }
+        Layout::Color {
+            red: Channel::new(red, width(red, [green, blue], total_bits)),
+            green: Channel::new(green, width(green, [red, blue], total_bits)),
+            blue: Channel::new(blue, width(blue, [red, green], total_bits)),
+        }
+    }
+
+    pub(crate) const fn bytes_per_pixel(&self) -> usize {
+        self.bytes_per_pixel
+    }
+
+    pub(crate) const fn encode(&self, color_rgb: (u8, u8, u8)) -> u32 {
+        match self.layout {
+            Layout::Color { red, green, blue } => {
+                red.encode(color_rgb.0) | green.encode(color_rgb.1) | blue.encode(color_rgb.2)
+            }
+            Layout::Gray { bits } => {
+                let luma = (color_rgb.0 as u32 * 77 + color_rgb.1 as u32 * 150 + color_rgb.2 as u32 * 29) >> 8;
+                if bits == 0 {
+                    0
+                } else if bits <= 8 {
+                    luma >> (8 - bits)
+                } else {
+                    (luma as u64 * ((1u64 << bits) - 1) / 255) as u32
+                }
+            }
+        }
+    }
+
+    pub(crate) const fn decode(&self, pixel: u32) -> (u8, u8, u8) {
+        match self.layout {
+            Layout::Color { red, green, blue } => (red.decode(pixel), green.decode(pixel), blue.decode(pixel)),
+            Layout::Gray { bits } => {
+                let luma = if bits == 0 {
+                    0
+                } else if bits <= 8 {
+                    (pixel & ((1 << bits) - 1)) * 255 / ((1 << bits) - 1)
+                } else {
+                    pixel >> (bits - 8) & 0xff
+                } as u8;
+                (luma, luma, luma)
+            }
+        }
+    }
+
+    /// Stores an encoded pixel at `destination`. Bytes past the fourth are zeroed.
+    ///
+    /// # Safety
+    /// `destination` must be valid for `bytes_per_pixel` bytes of writes.
+    pub(crate) unsafe fn write(&self, destination: *mut u8, pixel: u32) {
+        let bytes = pixel.to_le_bytes();
+        unsafe {
+            if self.bytes_per_pixel <= 4 {
+                ptr::copy_nonoverlapping(bytes.as_ptr(), destination, self.bytes_per_pixel);
+            } else {
+                ptr::copy_nonoverlapping(bytes.as_ptr(), destination, 4);
+                ptr::write_bytes(destination.add(4), 0, self.bytes_per_pixel - 4);
+            }
+        }
+    }
+
+    /// Loads the encoded pixel at `source`.
+    ///
+    /// # Safety
+    /// `source` must be valid for `bytes_per_pixel` bytes of reads.
+    pub(crate) unsafe fn read(&self, source: *const u8) -> u32 {
+        let mut bytes = [0; 4];
+        unsafe {
+            ptr::copy_nonoverlapping(source, bytes.as_mut_ptr(), self.bytes_per_pixel.min(4));
+        }
+        u32::from_le_bytes(bytes)
+    }
+}
@@ -0,0 +1,113 @@
+//! Screenshots of what is on screen, written as BMP or PPM files whatever
+//! the pixel format of the framebuffer.
+
+use core::fmt;
+
+use crate::{DisplayTextManager, Rect};
+use crate::cursor::inverted;
+
+/// Pixels converted per call to the sink.
+const CHUNK_PIXELS: usize = 64;
+
+/// Receives the bytes of a screenshot, for example to send them over a serial port.
+pub trait ByteSink {
+    fn write_bytes(&mut self, bytes: &[u8]) -> fmt::Result;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenshotFormat {
+    /// 24-bit uncompressed Windows bitmap.
+    Bmp,
+    /// Binary portable pixmap (`P6`).
+    Ppm,
+}
+
+/// Lets `write!` format headers straight into a sink.
+struct Text<'a, S>(&'a mut S);
+
+impl<S: ByteSink> fmt::Write for Text<'_, S> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write_bytes(s.as_bytes())
+    }
+}
+
+impl DisplayTextManager {
+    /// Writes the whole screen to `sink`, without the cursor. With a back
+    /// buffer this is what was last flushed, not what has been drawn since.
+    pub fn screenshot(&self, format: ScreenshotFormat, sink: &mut impl ByteSink) -> fmt::Result {
+        let screen = Rect::new((0, 0), (self.frame_info.width, self.frame_info.height));
+        self.screenshot_rect(screen, format, sink)
+    }
+
+    /// Writes the part of `area` that lies on screen to `sink`.
+    pub fn screenshot_rect(&self, area: Rect, format: ScreenshotFormat, sink: &mut impl ByteSink) -> fmt::Result {
+        let area = self
+            .clip_rect(
+                (area.x.min(isize::MAX as usize) as isize, area.y.min(isize::MAX as usize) as isize),
+                (area.width, area.height),
+            )
+            .unwrap_or(Rect::new((0, 0), (0, 0)));
+        match format {
+            ScreenshotFormat::Ppm => {
+                fmt::write(&mut Text(sink), format_args!("P6\n{} {}\n255\n", area.width, area.height))?;
+                for y in area.y..area.y + area.height {
+                    self.write_row(area, y, [0, 1, 2], 0, sink)?;
+                }
+            }
+            ScreenshotFormat::Bmp => {
+                // Rows are stored bottom up and padded to 4 bytes.
+                let row_len = (area.width * 3).next_multiple_of(4);
+                let pixels_offset = 14 + 40;
+                let mut header = [0u8; 54];
+                header[..2].copy_from_slice(b"BM");
+                header[2..6].copy_from_slice(&((pixels_offset + row_len * area.height) as u32).to_le_bytes());
+                header[10..14].copy_from_slice(&(pixels_offset as u32).to_le_bytes());
+                header[14..18].copy_from_slice(&40u32.to_le_bytes());
+                header[18..22].copy_from_slice(&(area.width as u32).to_le_bytes());
+                header[22..26].copy_from_slice(&(area.height as u32).to_le_bytes());
+                header[26..28].copy_from_slice(&1u16.to_le_bytes());
+                header[28..30].copy_from_slice(&24u16.to_le_bytes());
+                header[34..38].copy_from_slice(&((row_len * area.height) as u32).to_le_bytes());
+                sink.write_bytes(&header)?;
+                for y in (area.y..area.y + area.height).rev() {
+                    self.write_row(area, y, [2, 1, 0], row_len - area.width * 3, sink)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the on-screen pixels of row `y` within `area` as three bytes each,
+    /// with the RGB channels at the offsets in `order`, then `padding` zeros.
+    fn write_row(
+        &self,
+        area: Rect,
+        y: usize,
+        order: [usize; 3],
+        padding: usize,
+        sink: &mut impl ByteSink,
+    ) -> fmt::Result {
+        let mut chunk = [0u8; CHUNK_PIXELS * 3];
+        let mut x = area.x;
+        while x < area.x + area.width {
+            let count = (area.x + area.width - x).min(CHUNK_PIXELS);
+            for (i, bytes) in chunk.chunks_exact_mut(3).take(count).enumerate() {
+                let pixel = unsafe {
+                    self.pixel_encoder
+                        .read(self.buffer.add(self.pixel_offset((x + i, y))))
+                };
+                let mut color = self.pixel_encoder.decode(pixel);
+                if self.cursor_covers((x + i, y), true) {
+                    color = inverted(color);
+                }
+                let (red, green, blue) = color;
+                bytes[order[0]] = red;
+                bytes[order[1]] = green;
+                bytes[order[2]] = blue;
+            }
+            sink.write_bytes(&chunk[..count * 3])?;
+            x += count;
+        }
+        sink.write_bytes(&[0; 3][..padding])
+    }
+}
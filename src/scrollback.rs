@@ -0,0 +1,88 @@
+//! Text rows that scrolled off the top of the screen, kept in a ring of
+//! caller-supplied cells so the view can be moved back to them.
+
+use core::ptr::null_mut;
+
+use crate::Cell;
+
+/// Lines of `columns` cells, oldest first. When the ring is full, each new
+/// line replaces the oldest one.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Scrollback {
+    cells: *mut Cell,
+    capacity: usize,
+    columns: usize,
+    /// Index of the oldest line in the storage.
+    start: usize,
+    len: usize,
+}
+
+impl Scrollback {
+    pub(crate) const fn new() -> Self {
+        Scrollback {
+            cells: null_mut(),
+            capacity: 0,
+            columns: 0,
+            start: 0,
+            len: 0,
+        }
+    }
+
+    pub(crate) fn attach(&mut self, storage: &'static mut [Cell], columns: usize) {
+        *self = Scrollback {
+            cells: storage.as_mut_ptr(),
+            capacity: storage.len(),
+            columns,
+            start: 0,
+            len: 0,
+        };
+    }
+
+    /// Forgets every line and stores lines of `columns` cells from now on.
+    pub(crate) fn reset(&mut self, columns: usize) {
+        self.columns = columns;
+        self.start = 0;
+        self.len = 0;
+    }
+
+    /// Number of lines the storage holds.
+    fn max_lines(&self) -> usize {
+        if self.cells.is_null() || self.columns == 0 {
+            0
+        } else {
+            self.capacity / self.columns
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Appends `line`, dropping the oldest line if the ring is full. Lines of
+    /// another width than the ring's are ignored.
+    pub(crate) fn push(&mut self, line: &[Cell]) {
+        let max_lines = self.max_lines();
+        if max_lines == 0 || line.len() != self.columns {
+            return;
+        }
+        let slot = (self.start + self.len) % max_lines;
+        if self.len == max_lines {
+            self.start = (self.start + 1) % max_lines;
+        } else {
+            self.len += 1;
+        }
+        let columns = self.columns;
+        let storage = unsafe { core::slice::from_raw_parts_mut(self.cells, self.capacity) };
+        storage[slot * columns..(slot + 1) * columns].copy_from_slice(line);
+    }
+
+    /// The `index`th line, counting from the oldest.
+    pub(crate) fn line(&self, index: usize) -> Option<&[Cell]> {
+        if index >= self.len {
+            return None;
+        }
+        let slot = (self.start + index) % self.max_lines();
+        let storage = unsafe { core::slice::from_raw_parts(self.cells, self.capacity) };
+        Some(&storage[slot * self.columns..(slot + 1) * self.columns])
+    }
+}
@@ -0,0 +1,188 @@
+//! Searching the text on screen and in the scrollback, and highlighting what
+//! was found.
+
+use crate::{Attributes, Cell, DisplayTextManager};
+
+/// Longest pattern `search` accepts, in characters.
+pub const MAX_PATTERN_LEN: usize = 64;
+
+#[derive(Debug)]
+pub struct PatternTooLong;
+
+/// Where a match starts. Lines are counted from the oldest line of the
+/// scrollback, followed by the rows of the text buffer, so they shift once
+/// the scrollback is full and drops lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchMatch {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// The active search pattern and the match last moved to.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Search {
+    pattern: [char; MAX_PATTERN_LEN],
+    len: usize,
+    ignore_case: bool,
+    wildcards: bool,
+    pub(crate) current: Option<SearchMatch>,
+    /// Foreground and background colors of matched cells.
+    pub(crate) highlight: ((u8, u8, u8), (u8, u8, u8)),
+}
+
+impl Search {
+    pub(crate) const fn new() -> Self {
+        Search {
+            pattern: [' '; MAX_PATTERN_LEN],
+            len: 0,
+            ignore_case: false,
+            wildcards: false,
+            current: None,
+            highlight: ((0, 0, 0), (255, 255, 85)),
+        }
+    }
+
+    pub(crate) fn is_active(&self) -> bool {
+        self.len > 0
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the pattern matches `line` from `column` on.
+    fn matches_at(&self, line: &[Cell], column: usize) -> bool {
+        let Some(cells) = line.get(column..column + self.len) else {
+            return false;
+        };
+        cells.iter().zip(&self.pattern[..self.len]).all(|(cell, pattern)| {
+            (self.wildcards && *pattern == '?')
+                || cell.character == *pattern
+                || (self.ignore_case && cell.character.eq_ignore_ascii_case(pattern))
+        })
+    }
+
+    /// Whether the cell at `column` of `line` is part of a match.
+    fn covers(&self, line: &[Cell], column: usize) -> bool {
+        self.is_active() && ((column + 1).saturating_sub(self.len)..=column).any(|start| self.matches_at(line, start))
+    }
+}
+
+impl DisplayTextManager {
+    /// Searches for `pattern` in the text buffer and the scrollback, and
+    /// highlights every match until `clear_search`. With `wildcards`, a `?` in
+    /// the pattern matches any character, otherwise only a `?`; with
+    /// `ignore_case`, ASCII letters match either case. Matches do not span lines.
+    pub fn search(&mut self, pattern: &str, ignore_case: bool, wildcards: bool) -> Result<(), PatternTooLong> {
+        let mut search = Search {
+            ignore_case,
+            wildcards,
+            highlight: self.search.highlight,
+            ..Search::new()
+        };
+        for c in pattern.chars() {
+            *search.pattern.get_mut(search.len).ok_or(PatternTooLong)? = c;
+            search.len += 1;
+        }
+        self.search = search;
+        self.redraw_text();
+        Ok(())
+    }
+
+    /// Stops highlighting matches.
+    pub fn clear_search(&mut self) {
+        if self.search.is_active() {
+            self.search = Search {
+                highlight: self.search.highlight,
+                ..Search::new()
+            };
+            self.redraw_text();
+        }
+    }
+
+    /// Changes the colors matches are highlighted with.
+    pub fn set_search_highlight(&mut self, foreground: (u8, u8, u8), background: (u8, u8, u8)) {
+        self.search.highlight = (foreground, background);
+        if self.search.is_active() {
+            self.redraw_text();
+        }
+    }
+
+    /// Moves to the first match after the last one found, or from the top of
+    /// the view, towards newer text. The view scrolls to show it. Nothing is
+    /// found without a search pattern.
+    pub fn find_next(&mut self) -> Option<SearchMatch> {
+        if !self.search.is_active() {
+            return None;
+        }
+        let (mut line, mut column) = match self.search.current {
+            Some(current) => (current.line, current.column + 1),
+            None => (self.scrollback.len() - self.view_offset, 0),
+        };
+        while let Some(text) = self.text_line(line) {
+            if let Some(found) = (column..text.len()).find(|start| self.search.matches_at(text, *start)) {
+                return Some(self.show_match(SearchMatch { line, column: found }));
+            }
+            line += 1;
+            column = 0;
+        }
+        None
+    }
+
+    /// Moves to the last match before the last one found, or from the bottom
+    /// of the view, towards older text. The view scrolls to show it.
+    pub fn find_previous(&mut self) -> Option<SearchMatch> {
+        if !self.search.is_active() {
+            return None;
+        }
+        let (mut line, mut end) = match self.search.current {
+            Some(current) => (current.line, current.column),
+            None => ((self.scrollback.len() - self.view_offset + self.text_size().1).checked_sub(1)?, usize::MAX),
+        };
+        loop {
+            let text = self.text_line(line)?;
+            if let Some(found) = (0..end.min(text.len())).rev().find(|start| self.search.matches_at(text, *start)) {
+                return Some(self.show_match(SearchMatch { line, column: found }));
+            }
+            line = line.checked_sub(1)?;
+            end = usize::MAX;
+        }
+    }
+
+    /// Makes `found` the current match and scrolls the view as little as
+    /// possible to show its line.
+    fn show_match(&mut self, found: SearchMatch) -> SearchMatch {
+        self.search.current = Some(found);
+        let history = self.scrollback.len();
+        let rows = self.text_size().1;
+        let top = history - self.view_offset;
+        if found.line < top {
+            self.set_view_offset(history - found.line);
+        } else if found.line >= top + rows {
+            self.set_view_offset((history + rows - 1).saturating_sub(found.line));
+        }
+        found
+    }
+
+    /// The cells of `line`, counted as in `SearchMatch`.
+    pub(crate) fn text_line(&self, line: usize) -> Option<&[Cell]> {
+        match line.checked_sub(self.scrollback.len()) {
+            Some(row) => self.grid.row(row),
+            None => self.scrollback.line(line),
+        }
+    }
+
+    /// `cell`, shown at `column` of `line`, in the highlight colors if it is
+    /// part of a match.
+    pub(crate) fn highlighted(&self, line: usize, column: usize, cell: Cell) -> Cell {
+        match self.text_line(line) {
+            Some(text) if self.search.covers(text, column) => Cell {
+                foreground: self.search.highlight.0,
+                background: self.search.highlight.1,
+                attributes: cell.attributes.difference(Attributes::INVERSE),
+                ..cell
+            },
+            _ => cell,
+        }
+    }
+}
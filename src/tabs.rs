@@ -0,0 +1,50 @@
+//! Tab stops, as a bit per column.
+
+/// Columns past this many have no tab stops; a tab there moves to the last column.
+pub const MAX_TAB_COLUMNS: usize = 512;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TabStops {
+    bits: [u64; MAX_TAB_COLUMNS / WORD_BITS],
+}
+
+impl TabStops {
+    /// A stop every 8 columns, starting at column 8.
+    pub(crate) const fn new() -> Self {
+        // Bits 0, 8, 16, ... of every word, without the one for column 0.
+        let every_eighth = 0x0101_0101_0101_0101;
+        let mut bits = [every_eighth; MAX_TAB_COLUMNS / WORD_BITS];
+        bits[0] &= !1;
+        TabStops { bits }
+    }
+
+    pub(crate) fn set(&mut self, column: usize) {
+        if let Some(word) = self.bits.get_mut(column / WORD_BITS) {
+            *word |= 1 << (column % WORD_BITS);
+        }
+    }
+
+    pub(crate) fn clear(&mut self, column: usize) {
+        if let Some(word) = self.bits.get_mut(column / WORD_BITS) {
+            *word &= !(1 << (column % WORD_BITS));
+        }
+    }
+
+    pub(crate) fn clear_all(&mut self) {
+        self.bits = [0; MAX_TAB_COLUMNS / WORD_BITS];
+    }
+
+    fn is_set(&self, column: usize) -> bool {
+        self.bits
+            .get(column / WORD_BITS)
+            .is_some_and(|word| word & (1 << (column % WORD_BITS)) != 0)
+    }
+
+    /// The first stop after `column`, or the last of `columns` if there is none.
+    pub(crate) fn next(&self, column: usize, columns: usize) -> usize {
+        let last = columns.saturating_sub(1);
+        (column + 1..last).find(|column| self.is_set(*column)).unwrap_or(last)
+    }
+}
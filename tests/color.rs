@@ -0,0 +1,47 @@
+mod common;
+
+use ::common::bootloader_api::info::PixelFormat;
+use common::manager;
+use display_text__processor_graphics::{CompositeOp, Rgba};
+
+const BACKGROUND: (u8, u8, u8) = (0, 0, 200);
+const HALF_WHITE: Rgba = Rgba::new(255, 255, 255, 128);
+
+fn blended(op: CompositeOp, gamma_correct: bool) -> (u8, u8, u8) {
+    let mut display = manager(2, 2, PixelFormat::Bgr, 4);
+    display.fill_rect((0, 0), (2, 2), BACKGROUND);
+    display.set_gamma_correct(gamma_correct);
+    display.blend_pixel((1, 1), HALF_WHITE, op).unwrap();
+    display.get_pixel((1, 1)).unwrap()
+}
+
+#[test]
+fn porter_duff_operators_over_an_opaque_screen() {
+    assert_eq!(blended(CompositeOp::SourceOver, false), (128, 128, 228));
+    assert_eq!(blended(CompositeOp::Source, false), (128, 128, 128));
+    assert_eq!(blended(CompositeOp::SourceAtop, false), (128, 128, 228));
+    assert_eq!(blended(CompositeOp::Destination, false), BACKGROUND);
+    assert_eq!(blended(CompositeOp::DestinationOver, false), BACKGROUND);
+    assert_eq!(blended(CompositeOp::DestinationIn, false), (0, 0, 100));
+    assert_eq!(blended(CompositeOp::DestinationOut, false), (0, 0, 100));
+    assert_eq!(blended(CompositeOp::Xor, false), (0, 0, 100));
+    assert_eq!(blended(CompositeOp::Clear, false), (0, 0, 0));
+}
+
+#[test]
+fn gamma_correct_blending_mixes_linear_light() {
+    let (r, _, b) = blended(CompositeOp::SourceOver, true);
+    // Half of full intensity is about 188 in sRGB, not 128.
+    assert_eq!(r, 188);
+    assert!(b > 228);
+    assert_eq!(blended(CompositeOp::Destination, true), BACKGROUND);
+}
+
+#[test]
+fn opaque_source_over_replaces_the_pixel() {
+    let mut display = manager(4, 4, PixelFormat::Bgr, 4);
+    display.blend_rect((-2, -2), (4, 4), Rgba::opaque((10, 20, 30)), CompositeOp::SourceOver);
+    assert_eq!(display.get_pixel((1, 1)).unwrap(), (10, 20, 30));
+    assert_eq!(display.get_pixel((2, 2)).unwrap(), (0, 0, 0));
+    assert!(display.blend_pixel((4, 0), HALF_WHITE, CompositeOp::SourceOver).is_err());
+}
@@ -0,0 +1,124 @@
+//! Golden-image snapshots: the screen of a manager is compared with a PPM file
+//! checked in under `tests/golden`. Run with `UPDATE_GOLDEN=1` to (re)write the
+//! files after an intended rendering change. On a mismatch the actual image and
+//! a diff image (differences in red over a dimmed copy of the expected image)
+//! are written next to the test binaries, and their paths are in the panic message.
+
+use std::{env, fs, path::PathBuf};
+
+use ::common::bootloader_api::info::PixelFormat;
+use display_text__processor_graphics::DisplayTextManager;
+
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub rgb: Vec<u8>,
+}
+
+impl Image {
+    /// Reads the screen of `manager`, converted to RGB. Supports the 24 and
+    /// 32-bit RGB and BGR layouts the snapshot tests use.
+    pub fn from_screen(manager: &DisplayTextManager) -> Image {
+        let info = manager.frame_info();
+        let mut rgb = Vec::with_capacity(info.width * info.height * 3);
+        for y in 0..info.height {
+            for x in 0..info.width {
+                let offset = (y * info.stride + x) * info.bytes_per_pixel;
+                let pixel = &manager.framebuffer()[offset..offset + 3];
+                match info.pixel_format {
+                    PixelFormat::Rgb => rgb.extend_from_slice(pixel),
+                    PixelFormat::Bgr => rgb.extend_from_slice(&[pixel[2], pixel[1], pixel[0]]),
+                    format => panic!("snapshots do not support {format:?}"),
+                }
+            }
+        }
+        Image {
+            width: info.width,
+            height: info.height,
+            rgb,
+        }
+    }
+
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend_from_slice(&self.rgb);
+        ppm
+    }
+
+    pub fn from_ppm(data: &[u8]) -> Option<Image> {
+        let mut fields = Vec::new();
+        let mut position = 0;
+        while fields.len() < 4 {
+            while data.get(position)?.is_ascii_whitespace() {
+                position += 1;
+            }
+            let start = position;
+            while !data.get(position)?.is_ascii_whitespace() {
+                position += 1;
+            }
+            fields.push(std::str::from_utf8(&data[start..position]).ok()?);
+        }
+        let width = fields[1].parse().ok()?;
+        let height = fields[2].parse().ok()?;
+        if fields[0] != "P6" || fields[3] != "255" {
+            return None;
+        }
+        let rgb = data.get(position + 1..position + 1 + width * height * 3)?.to_vec();
+        Some(Image { width, height, rgb })
+    }
+}
+
+pub fn assert_golden(name: &str, manager: &DisplayTextManager) {
+    let actual = Image::from_screen(manager);
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden").join(format!("{name}.ppm"));
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual.to_ppm()).unwrap();
+        return;
+    }
+    let expected = fs::read(&path)
+        .ok()
+        .and_then(|data| Image::from_ppm(&data))
+        .unwrap_or_else(|| panic!("missing or invalid golden image {}; run with UPDATE_GOLDEN=1", path.display()));
+
+    let output = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("golden");
+    fs::create_dir_all(&output).unwrap();
+    let actual_path = output.join(format!("{name}.actual.ppm"));
+    let diff_path = output.join(format!("{name}.diff.ppm"));
+    if (expected.width, expected.height) != (actual.width, actual.height) {
+        fs::write(&actual_path, actual.to_ppm()).unwrap();
+        panic!(
+            "{name}: expected a {}x{} image, rendered {}x{} (see {})",
+            expected.width,
+            expected.height,
+            actual.width,
+            actual.height,
+            actual_path.display()
+        );
+    }
+
+    let mut diff = Image {
+        width: actual.width,
+        height: actual.height,
+        rgb: Vec::with_capacity(actual.rgb.len()),
+    };
+    let mut differences = 0;
+    for (expected, actual) in expected.rgb.chunks(3).zip(actual.rgb.chunks(3)) {
+        if expected == actual {
+            diff.rgb.extend(expected.iter().map(|channel| channel / 4));
+        } else {
+            differences += 1;
+            diff.rgb.extend_from_slice(&[255, 0, 0]);
+        }
+    }
+    if differences > 0 {
+        fs::write(&actual_path, actual.to_ppm()).unwrap();
+        fs::write(&diff_path, diff.to_ppm()).unwrap();
+        panic!(
+            "{name}: {differences} pixels differ from {} (actual: {}, diff: {})",
+            path.display(),
+            actual_path.display(),
+            diff_path.display()
+        );
+    }
+}
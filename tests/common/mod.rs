@@ -0,0 +1,43 @@
+#![allow(dead_code)]
+
+pub mod golden;
+
+use common::bootloader_api::info::{FrameBufferInfo, PixelFormat};
+use display_text__processor_graphics::DisplayTextManager;
+
+/// A manager drawing into a leaked, zeroed in-memory framebuffer.
+pub fn manager(width: usize, height: usize, pixel_format: PixelFormat, bytes_per_pixel: usize) -> DisplayTextManager {
+    manager_with_stride(width, height, width, pixel_format, bytes_per_pixel)
+}
+
+pub fn manager_with_stride(
+    width: usize,
+    height: usize,
+    stride: usize,
+    pixel_format: PixelFormat,
+    bytes_per_pixel: usize,
+) -> DisplayTextManager {
+    let byte_len = height * stride * bytes_per_pixel;
+    let buffer = Box::leak(vec![0u8; byte_len].into_boxed_slice());
+    let frame_info = FrameBufferInfo {
+        byte_len,
+        width,
+        height,
+        pixel_format,
+        bytes_per_pixel,
+        stride,
+    };
+    DisplayTextManager::new(buffer, frame_info).unwrap()
+}
+
+/// The bytes of the pixel at `(x, y)` on screen.
+pub fn pixel(manager: &DisplayTextManager, x: usize, y: usize) -> &[u8] {
+    let info = manager.frame_info();
+    let offset = (y * info.stride + x) * info.bytes_per_pixel;
+    &manager.framebuffer()[offset..offset + info.bytes_per_pixel]
+}
+
+/// Whether the pixel at `(x, y)` is anything but black.
+pub fn is_lit(manager: &DisplayTextManager, x: usize, y: usize) -> bool {
+    pixel(manager, x, y).iter().any(|byte| *byte != 0)
+}
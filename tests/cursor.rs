@@ -0,0 +1,187 @@
+mod common;
+
+use ::common::bootloader_api::info::PixelFormat;
+use common::{is_lit, manager, pixel};
+use display_text__processor_graphics::{ByteSink, CompositeOp, CursorStyle, DISPLAY_TEXT, DisplayTextManager, Font, Rgba, ScreenshotFormat};
+
+/// How many pixels of the 10x10 cell at `(column, row)` are lit.
+fn lit_in_cell(display: &DisplayTextManager, column: usize, row: usize) -> usize {
+    (0..10)
+        .flat_map(|y| (0..10).map(move |x| (column * 10 + x, row * 10 + y)))
+        .filter(|(x, y)| is_lit(display, *x, *y))
+        .count()
+}
+
+#[test]
+fn the_cursor_is_drawn_over_the_cell_it_is_on() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.print("A");
+    let text = display.framebuffer().to_vec();
+    assert!(!display.cursor_visible());
+    display.set_cursor_visible(true);
+    assert_eq!(lit_in_cell(&display, 1, 0), 100);
+    assert_eq!(pixel(&display, 15, 5), [255, 255, 255, 0]);
+
+    // Over a glyph, the glyph shows through inverted.
+    display.print("\x1b[D");
+    assert_eq!(lit_in_cell(&display, 1, 0), 0);
+    for y in 0..10 {
+        for x in 0..10 {
+            let offset = (y * 40 + x) * 4;
+            assert_eq!(pixel(&display, x, y)[0], 255 - text[offset]);
+        }
+    }
+
+    display.set_cursor_visible(false);
+    assert!(display.framebuffer() == &text[..]);
+}
+
+#[test]
+fn cursor_styles_cover_part_of_the_cell() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.set_cursor_visible(true);
+    display.set_cursor_style(CursorStyle::Underline);
+    assert_eq!(display.cursor_style(), CursorStyle::Underline);
+    assert_eq!(lit_in_cell(&display, 0, 0), 10);
+    assert!((0..10).all(|x| is_lit(&display, x, 9)));
+    display.set_cursor_style(CursorStyle::Bar);
+    assert_eq!(lit_in_cell(&display, 0, 0), 10);
+    assert!((0..10).all(|y| is_lit(&display, 0, y)));
+    display.set_cursor_style(CursorStyle::Block);
+    assert_eq!(lit_in_cell(&display, 0, 0), 100);
+}
+
+#[test]
+fn printing_moves_the_cursor_without_leaving_marks() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.set_cursor_visible(true);
+    display.print("ab\ncdef\ngh\n");
+    assert_eq!(display.cursor(), (0, 2));
+    assert_eq!(lit_in_cell(&display, 0, 2), 100);
+
+    let mut expected = manager(40, 30, PixelFormat::Bgr, 4);
+    expected.print("ab\ncdef\ngh\n");
+    display.print("\x1b[?25l");
+    assert!(!display.cursor_visible());
+    assert!(display.framebuffer() == expected.framebuffer());
+    display.print("\x1b[?25h");
+    assert_eq!(lit_in_cell(&display, 0, 2), 100);
+}
+
+#[test]
+fn the_cursor_blinks_with_ticks() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.set_cursor_visible(true);
+    display.tick();
+    assert_eq!(lit_in_cell(&display, 0, 0), 100);
+
+    display.set_cursor_blink(Some(2));
+    display.tick();
+    assert_eq!(lit_in_cell(&display, 0, 0), 100);
+    display.tick();
+    assert_eq!(lit_in_cell(&display, 0, 0), 0);
+    display.tick();
+    display.tick();
+    assert_eq!(lit_in_cell(&display, 0, 0), 100);
+    display.tick();
+    display.tick();
+    // Printing shows the cursor again for a whole period.
+    display.print(" ");
+    assert_eq!(lit_in_cell(&display, 1, 0), 100);
+    display.tick();
+    assert_eq!(lit_in_cell(&display, 1, 0), 100);
+
+    display.set_cursor_blink(None);
+    (0..10).for_each(|_| display.tick());
+    assert_eq!(lit_in_cell(&display, 1, 0), 100);
+}
+
+#[test]
+fn the_cursor_survives_redraws() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.set_text_buffer(Box::leak(vec![Default::default(); 12].into_boxed_slice())).unwrap();
+    display.set_scrollback_buffer(Box::leak(vec![Default::default(); 12].into_boxed_slice())).unwrap();
+    display.set_cursor_visible(true);
+    display.print("a\nb\nc\nd");
+    assert_eq!(lit_in_cell(&display, 1, 2), 100);
+    display.redraw_text();
+    assert_eq!(lit_in_cell(&display, 1, 2), 100);
+    // Scrolled back, the cursor is not on screen.
+    display.scroll_view_up(1);
+    assert_eq!(lit_in_cell(&display, 1, 2), 0);
+    display.scroll_to_bottom();
+    assert_eq!(lit_in_cell(&display, 1, 2), 100);
+    display.fill((0, 0, 0));
+    assert_eq!(lit_in_cell(&display, 1, 2), 100);
+    display.set_cursor_visible(false);
+    assert!(display.framebuffer().iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn drawing_over_the_cursor_keeps_its_colors() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.set_cursor_visible(true);
+    display.fill_rect((0, 0), (5, 5), (200, 0, 0));
+    display.blend_rect((0, 0), (2, 2), Rgba::new(0, 0, 200, 255), CompositeOp::SourceOver);
+    display.draw_line((0, 9), (9, 9), (0, 200, 0));
+    // The cursor is still drawn, over the new pixels.
+    assert_eq!(pixel(&display, 3, 3), [255, 255, 55, 0]);
+    assert_eq!(display.get_pixel((3, 3)).unwrap(), (200, 0, 0));
+    assert_eq!(display.get_pixel((1, 1)).unwrap(), (0, 0, 200));
+    assert_eq!(display.get_pixel((5, 9)).unwrap(), (0, 200, 0));
+    assert_eq!(display.get_pixel((7, 7)).unwrap(), (0, 0, 0));
+
+    display.set_cursor_visible(false);
+    assert_eq!(pixel(&display, 3, 3), [0, 0, 200, 0]);
+    assert_eq!(pixel(&display, 1, 1), [200, 0, 0, 0]);
+    assert_eq!(pixel(&display, 5, 9), [0, 200, 0, 0]);
+    assert!(!is_lit(&display, 7, 7));
+}
+
+struct Bytes(Vec<u8>);
+
+impl ByteSink for Bytes {
+    fn write_bytes(&mut self, bytes: &[u8]) -> core::fmt::Result {
+        self.0.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+#[test]
+fn screenshots_leave_the_cursor_out() {
+    let mut display = manager(2, 1, PixelFormat::Bgr, 4);
+    display.set_font(Font::from_psf(Box::leak(one_pixel_font().into_boxed_slice())).unwrap());
+    display.set_cursor_visible(true);
+    assert!(is_lit(&display, 0, 0));
+    let mut out = Bytes(Vec::new());
+    display.screenshot(ScreenshotFormat::Ppm, &mut out).unwrap();
+    assert!(out.0.ends_with(&[0; 6]));
+
+    // With a back buffer, the screen has the cursor where it was last flushed.
+    display.set_back_buffer(Box::leak(vec![0; 8].into_boxed_slice())).unwrap();
+    display.print(" ");
+    out.0.clear();
+    display.screenshot(ScreenshotFormat::Ppm, &mut out).unwrap();
+    assert!(out.0.ends_with(&[0; 6]));
+    display.flush();
+    assert!(is_lit(&display, 1, 0) && !is_lit(&display, 0, 0));
+    out.0.clear();
+    display.screenshot(ScreenshotFormat::Ppm, &mut out).unwrap();
+    assert!(out.0.ends_with(&[0; 6]));
+}
+
+/// A PSF2 font of 1x1 glyphs, so every pixel is a text cell.
+fn one_pixel_font() -> Vec<u8> {
+    let mut data = Vec::new();
+    for field in [0x864a_b572u32, 0, 32, 0, 128, 1, 1, 1] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    data.resize(32 + 128, 0);
+    data
+}
+
+#[test]
+fn ticking_from_an_interrupt_skips_a_locked_display() {
+    let _locked = DISPLAY_TEXT.lock();
+    display_text__processor_graphics::tick();
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use ::common::bootloader_api::info::PixelFormat;
+use common::{is_lit, manager};
+
+use display_text__processor_graphics::Rect;
+
+fn covers(rect: &Rect, (x, y): (usize, usize)) -> bool {
+    (rect.x..rect.x + rect.width).contains(&x) && (rect.y..rect.y + rect.height).contains(&y)
+}
+
+#[test]
+fn overlapping_and_adjacent_areas_are_merged() {
+    let mut display = manager(100, 100, PixelFormat::Bgr, 4);
+    display.fill_rect((0, 0), (10, 10), (255, 255, 255));
+    display.fill_rect((5, 5), (10, 10), (255, 255, 255));
+    assert_eq!(display.damage(), [Rect::new((0, 0), (15, 15))]);
+
+    display.clear_damage();
+    display.fill_rect((20, 0), (10, 5), (255, 255, 255));
+    display.fill_rect((20, 5), (10, 5), (255, 255, 255));
+    assert_eq!(display.damage(), [Rect::new((20, 0), (10, 10))]);
+
+    display.fill_rect((50, 50), (2, 2), (255, 255, 255));
+    assert_eq!(display.damage().len(), 2);
+}
+
+#[test]
+fn full_damage_list_merges_into_the_nearest_area() {
+    let mut display = manager(120, 120, PixelFormat::Bgr, 4);
+    let points: Vec<_> = (0..17).map(|i| (i * 6, i * 6)).collect();
+    for point in &points[..16] {
+        display.set_pixel(*point, (255, 255, 255)).unwrap();
+    }
+    assert_eq!(display.damage().len(), 16);
+    display.set_pixel(points[16], (255, 255, 255)).unwrap();
+    assert_eq!(display.damage().len(), 16);
+    assert!(display.damage().contains(&Rect::new((90, 90), (7, 7))));
+    for point in points {
+        assert!(display.damage().iter().any(|rect| covers(rect, point)), "{point:?} lost");
+    }
+}
+
+#[test]
+fn clear_damage_forgets_every_area() {
+    let mut display = manager(10, 10, PixelFormat::Bgr, 4);
+    display.fill_rect((0, 0), (3, 3), (255, 255, 255));
+    display.set_pixel((9, 9), (255, 255, 255)).unwrap();
+    assert!(!display.damage().is_empty());
+    display.clear_damage();
+    assert!(display.damage().is_empty());
+}
+
+#[test]
+fn flush_copies_only_damaged_areas() {
+    let mut display = manager(10, 10, PixelFormat::Bgr, 4);
+    display.set_back_buffer(Box::leak(vec![0; 400].into_boxed_slice())).unwrap();
+    display.fill_rect((0, 0), (2, 2), (255, 255, 255));
+    display.clear_damage();
+    display.fill_rect((5, 5), (2, 2), (255, 255, 255));
+    assert!(!is_lit(&display, 5, 5));
+    display.flush();
+    assert!(display.damage().is_empty());
+    assert!(is_lit(&display, 5, 5) && is_lit(&display, 6, 6));
+    assert!(!is_lit(&display, 0, 0) && !is_lit(&display, 1, 1));
+    assert!(!is_lit(&display, 4, 4) && !is_lit(&display, 7, 7));
+}
@@ -0,0 +1,169 @@
+mod common;
+
+use ::common::bootloader_api::info::PixelFormat;
+use common::{golden::assert_golden, is_lit, manager};
+
+use display_text__processor_graphics::MAX_RADIUS;
+
+const WHITE: (u8, u8, u8) = (255, 255, 255);
+
+#[test]
+fn shapes_are_clipped_to_the_screen() {
+    let mut display = manager(20, 10, PixelFormat::Bgr, 4);
+    display.draw_line((-50, -50), (100, 100), WHITE);
+    display.fill_circle((19, 9), 30, WHITE);
+    display.draw_ellipse((-5, 5), (40, 3), WHITE);
+    display.draw_rect((-3, -3), (100, 100), WHITE);
+    display.fill_rect((isize::MIN, isize::MIN), (usize::MAX, usize::MAX), WHITE);
+    display.draw_hline((isize::MAX - 1, 3), usize::MAX, WHITE);
+    assert!((0..10).all(|y| (0..20).all(|x| is_lit(&display, x, y))));
+}
+
+#[test]
+fn extreme_coordinates_do_not_overflow() {
+    let mut display = manager(20, 10, PixelFormat::Bgr, 4);
+    display.draw_line((isize::MIN, 0), (0, 1), WHITE);
+    display.draw_line((isize::MIN, isize::MIN), (isize::MAX, isize::MAX), WHITE);
+    display.draw_line((isize::MAX, 3), (isize::MIN, 3), WHITE);
+    display.draw_line((5, isize::MIN), (5, isize::MAX), WHITE);
+    for radius in [MAX_RADIUS + 1, usize::MAX] {
+        display.draw_circle((0, 0), radius, WHITE);
+        display.fill_circle((0, 0), radius, WHITE);
+        display.fill_ellipse((0, 0), (radius, 1), WHITE);
+    }
+    display.fill_circle((isize::MAX, isize::MIN), MAX_RADIUS, WHITE);
+    display.draw_ellipse((isize::MIN, isize::MAX), (MAX_RADIUS, MAX_RADIUS), WHITE);
+    assert!(is_lit(&display, 5, 0) && is_lit(&display, 0, 3) && is_lit(&display, 19, 3));
+    for rect in display.damage() {
+        assert!(rect.x + rect.width <= 20 && rect.y + rect.height <= 10);
+    }
+
+    let mut display = manager(20, 10, PixelFormat::Bgr, 4);
+    display.fill_circle((10, 5), MAX_RADIUS, WHITE);
+    assert!((0..10).all(|y| (0..20).all(|x| is_lit(&display, x, y))));
+}
+
+/// The pixels Bresenham's algorithm picks from `start` to `end`.
+fn bresenham(start: (isize, isize), end: (isize, isize)) -> Vec<(isize, isize)> {
+    let delta = ((end.0 - start.0).abs(), -(end.1 - start.1).abs());
+    let step = ((end.0 - start.0).signum(), (end.1 - start.1).signum());
+    let mut error = delta.0 + delta.1;
+    let mut point = start;
+    let mut points = vec![point];
+    while point != end {
+        let doubled = 2 * error;
+        if doubled >= delta.1 {
+            error += delta.1;
+            point.0 += step.0;
+        }
+        if doubled <= delta.0 {
+            error += delta.0;
+            point.1 += step.1;
+        }
+        points.push(point);
+    }
+    points
+}
+
+#[test]
+fn clipped_lines_keep_their_pixels() {
+    let ends = [(-37, -5), (-3, 20), (4, -11), (12, 3), (25, 14), (31, -2), (-8, 9), (19, 30)];
+    for start in ends {
+        for end in ends {
+            let mut display = manager(20, 10, PixelFormat::Bgr, 4);
+            display.draw_line(start, end, WHITE);
+            let expected = bresenham(start, end);
+            for y in 0..10 {
+                for x in 0..20 {
+                    let on_line = expected.contains(&(x, y));
+                    assert_eq!(is_lit(&display, x as usize, y as usize), on_line, "{start:?} to {end:?} at {x}, {y}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn line_endpoints_are_included() {
+    let mut display = manager(20, 10, PixelFormat::Bgr, 4);
+    display.draw_line((2, 1), (15, 7), WHITE);
+    assert!(is_lit(&display, 2, 1));
+    assert!(is_lit(&display, 15, 7));
+    let lit = (0..10).flat_map(|y| (0..20).map(move |x| (x, y))).filter(|(x, y)| is_lit(&display, *x, *y));
+    assert_eq!(lit.count(), 14);
+}
+
+#[test]
+fn rect_outline_leaves_the_inside_untouched() {
+    let mut display = manager(20, 10, PixelFormat::Bgr, 4);
+    display.draw_rect((2, 2), (5, 4), WHITE);
+    assert!(is_lit(&display, 2, 2) && is_lit(&display, 6, 5));
+    assert!(!is_lit(&display, 3, 3) && !is_lit(&display, 7, 5));
+}
+
+#[test]
+fn primitives() {
+    let mut display = manager(120, 80, PixelFormat::Bgr, 4);
+    display.draw_line((2, 2), (60, 30), (255, 80, 80));
+    display.draw_line((2, 30), (20, 2), (80, 255, 80));
+    display.draw_rect((65, 2), (50, 28), (80, 80, 255));
+    display.fill_rect((70, 7), (20, 10), (255, 255, 0));
+    display.draw_circle((20, 55), 18, (255, 255, 255));
+    display.fill_circle((20, 55), 8, (0, 200, 200));
+    display.draw_ellipse((70, 55), (40, 15), (255, 0, 255));
+    display.fill_ellipse((70, 55), (20, 6), (255, 128, 0));
+    assert_golden("primitives", &display);
+}
+
+#[test]
+fn antialiased_line_blends_with_the_background() {
+    let mut display = manager(20, 10, PixelFormat::Bgr, 4);
+    display.fill_rect((0, 0), (20, 10), (0, 0, 200));
+    display.draw_line_aa((0.5, 2.0), (19.5, 2.0), (255, 255, 255));
+    assert_eq!(display.get_pixel((10, 1)).unwrap(), (128, 128, 228));
+    assert_eq!(display.get_pixel((10, 2)).unwrap(), (128, 128, 228));
+    assert_eq!(display.get_pixel((10, 3)).unwrap(), (0, 0, 200));
+}
+
+#[test]
+fn antialiased_arc_stays_within_its_angles() {
+    let mut display = manager(40, 40, PixelFormat::Bgr, 4);
+    display.draw_arc_aa((20, 20), 10.0, 0.0, 90.0, WHITE);
+    assert!(is_lit(&display, 30, 20) && is_lit(&display, 20, 30));
+    assert!(!is_lit(&display, 10, 20) && !is_lit(&display, 20, 10));
+    let lit = (0..40).flat_map(|y| (0..40).map(move |x| (x, y))).filter(|(x, y)| is_lit(&display, *x, *y));
+    assert!(lit.clone().all(|(x, y)| x >= 20 && y >= 20));
+    assert!(lit.count() > 10);
+}
+
+#[test]
+fn antialiased_extremes_are_clipped_or_ignored() {
+    let mut display = manager(40, 40, PixelFormat::Bgr, 4);
+    display.draw_line_aa((f32::NEG_INFINITY, 5.0), (10.0, 5.0), WHITE);
+    display.draw_line_aa((2.0, f32::NAN), (10.0, 5.0), WHITE);
+    display.draw_line_aa((-f32::MAX, 5.0), (f32::MAX, 5.0), WHITE);
+    display.draw_circle_aa((20, 20), f32::INFINITY, WHITE);
+    display.draw_circle_aa((20, 20), 1e19, WHITE);
+    display.draw_circle_aa((20, 20), MAX_RADIUS as f32 + 1.0, WHITE);
+    display.draw_circle_aa((isize::MIN, isize::MAX), 10.0, WHITE);
+    display.draw_arc_aa((isize::MAX, 20), MAX_RADIUS as f32, 0.0, 360.0, WHITE);
+    display.draw_arc_aa((20, 20), 10.0, 0.0, f32::INFINITY, WHITE);
+    assert!((0..40).all(|y| (0..40).all(|x| !is_lit(&display, x, y))));
+
+    display.draw_line_aa((-1e9, 5.5), (1e9, 6.5), WHITE);
+    assert!((0..40).all(|x| is_lit(&display, x, 5)));
+    display.draw_line_aa((30.5, -1e9), (30.5, 1e9), WHITE);
+    assert!((0..40).all(|y| is_lit(&display, 30, y)));
+}
+
+#[test]
+fn antialiased() {
+    let mut display = manager(120, 80, PixelFormat::Bgr, 4);
+    display.fill_rect((60, 0), (60, 80), (40, 40, 90));
+    display.draw_line_aa((2.5, 2.5), (110.5, 30.0), (255, 255, 255));
+    display.draw_line_aa((10.0, 75.0), (30.0, 5.0), (255, 200, 80));
+    display.draw_circle_aa((40, 50), 20.5, (80, 255, 80));
+    display.draw_circle_aa((90, 50), 6.3, (255, 255, 255));
+    display.draw_arc_aa((90, 50), 20.0, 200.0, 20.0, (255, 80, 80));
+    assert_golden("antialiased", &display);
+}
@@ -0,0 +1,211 @@
+use display_text__processor_graphics::{Font, FontError, Glyph};
+
+fn is_fallback_block(glyph: &Glyph) -> bool {
+    let (width, height) = glyph.size();
+    (0..height).all(|y| (0..width).all(|x| glyph.pixel(x, y)))
+}
+
+#[test]
+fn builtin_font_covers_printable_ascii() {
+    for c in (0x20u8..=0x7e).map(char::from) {
+        let glyph = Font::Builtin.glyph(c);
+        assert!(glyph.is_some(), "no glyph for {c:?}");
+        assert!(!is_fallback_block(&glyph.unwrap()), "{c:?} renders as the fallback block");
+    }
+}
+
+#[test]
+fn builtin_font_falls_back_to_block() {
+    assert!(Font::Builtin.glyph('\u{1F600}').is_none());
+    assert!(is_fallback_block(&Font::Builtin.fallback_glyph()));
+}
+
+#[cfg(feature = "latin1")]
+#[test]
+fn builtin_font_covers_latin1() {
+    for c in '\u{a0}'..='\u{ff}' {
+        let glyph = Font::Builtin.glyph(c);
+        assert!(glyph.is_some(), "no glyph for {c:?}");
+        assert!(!is_fallback_block(&glyph.unwrap()), "{c:?} renders as the fallback block");
+    }
+}
+
+#[cfg(feature = "cp437")]
+#[test]
+fn builtin_font_covers_cp437() {
+    const UPPER_HALF: &str = "ÇüéâäàåçêëèïîìÄÅÉæÆôöòûùÿÖÜ¢£¥₧ƒáíóúñÑªº¿⌐¬½¼¡«»\
+        ░▒▓│┤╡╢╖╕╣║╗╝╜╛┐└┴┬├─┼╞╟╚╔╩╦╠═╬╧╨╤╥╙╘╒╓╫╪┘┌█▄▌▐▀\
+        αßΓπΣσµτΦΘΩδ∞φε∩≡±≥≤⌠⌡÷≈°∙·√ⁿ²■\u{a0}";
+    const CONTROL_GLYPHS: &str = "☺☻♥♦♣♠•◘○◙♂♀♪♫☼►◄↕‼¶§▬↨↑↓→←∟↔▲▼⌂";
+    for c in UPPER_HALF.chars().chain(CONTROL_GLYPHS.chars()) {
+        assert!(Font::Builtin.glyph(c).is_some(), "no glyph for {c:?}");
+    }
+    assert_eq!(UPPER_HALF.chars().count(), 128);
+}
+
+#[cfg(feature = "cp437")]
+#[test]
+fn box_drawing_glyphs_join_across_cell_spacing() {
+    let horizontal = Font::Builtin.glyph('─').unwrap();
+    let row = (0..8).find(|y| horizontal.pixel(0, *y)).unwrap();
+    assert!(horizontal.pixel(9, row));
+    assert!(!Font::Builtin.glyph('-').unwrap().pixel(9, 4));
+}
+
+/// A PSF1 font of `height` rows whose glyphs store their own index in the
+/// first two rows, followed by `table`.
+fn psf1(mode: u8, height: u8, table: &[u16]) -> Vec<u8> {
+    let glyph_count = if mode & 0x01 != 0 { 512 } else { 256 };
+    let mut data = vec![0x36, 0x04, mode, height];
+    for index in 0..glyph_count as u16 {
+        let mut glyph = vec![0; height as usize];
+        glyph[..2].copy_from_slice(&index.to_le_bytes());
+        data.extend_from_slice(&glyph);
+    }
+    for value in table {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data
+}
+
+/// A PSF2 font of 8x4 glyphs like `psf1`'s, followed by `table`.
+fn psf2(glyph_count: u32, table: &[u8]) -> Vec<u8> {
+    let flags = u32::from(!table.is_empty());
+    let mut data = Vec::new();
+    for field in [0x864a_b572u32, 0, 32, flags, glyph_count, 4, 4, 8] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    for index in 0..glyph_count as u16 {
+        data.extend_from_slice(&index.to_le_bytes());
+        data.extend_from_slice(&[0, 0]);
+    }
+    data.extend_from_slice(table);
+    data
+}
+
+fn parse(data: Vec<u8>) -> Result<Font, FontError> {
+    Font::from_psf(Box::leak(data.into_boxed_slice()))
+}
+
+/// The index of the glyph `c` is drawn with, as stored by `psf1` and `psf2`.
+fn glyph_index(font: &Font, c: char) -> Option<usize> {
+    match font.glyph(c)? {
+        Glyph::Bitmap { data, .. } => Some(usize::from(u16::from_le_bytes([data[0], data[1]]))),
+        Glyph::Builtin { .. } => panic!("a PSF font returned a built-in glyph"),
+    }
+}
+
+#[test]
+fn psf1_without_table_maps_characters_to_glyph_indices() {
+    let font = parse(psf1(0x00, 16, &[])).unwrap();
+    assert_eq!(font.cell_size(), (8, 16));
+    assert_eq!(glyph_index(&font, 'A'), Some(65));
+    assert_eq!(glyph_index(&font, '\u{ff}'), Some(255));
+    assert_eq!(glyph_index(&font, '\u{100}'), None);
+}
+
+#[test]
+fn psf1_512_glyph_mode_reads_the_upper_half() {
+    let font = parse(psf1(0x01, 8, &[])).unwrap();
+    let Font::Psf(psf) = font else { unreachable!() };
+    assert_eq!(psf.glyph_count(), 512);
+    assert_eq!(glyph_index(&font, '\u{12c}'), Some(300));
+    assert_eq!(glyph_index(&font, '\u{1ff}'), Some(511));
+    assert_eq!(glyph_index(&font, '\u{200}'), None);
+}
+
+#[test]
+fn psf1_unicode_table_skips_sequences() {
+    let mut table = vec![
+        0x41, 0x3a9, 0xffff, // glyph 0: 'A' and 'Ω'
+        0x42, 0xfffe, 0x43, 0x301, 0xffff, // glyph 1: 'B', and the sequence "C\u{301}"
+        0x43, 0xffff, // glyph 2: 'C'
+    ];
+    table.extend((3..256).map(|_| 0xffff));
+    let font = parse(psf1(0x02, 8, &table)).unwrap();
+    assert_eq!(glyph_index(&font, 'A'), Some(0));
+    assert_eq!(glyph_index(&font, 'Ω'), Some(0));
+    assert_eq!(glyph_index(&font, 'B'), Some(1));
+    assert_eq!(glyph_index(&font, 'C'), Some(2));
+    assert_eq!(glyph_index(&font, '\u{301}'), None);
+    assert_eq!(glyph_index(&font, 'a'), None);
+}
+
+#[test]
+fn psf2_unicode_table_decodes_utf8() {
+    // Per glyph: its characters, then 0xFE before each sequence, then 0xFF.
+    let mut table = Vec::new();
+    for (characters, sequence) in [("é", None), ("€", Some("e\u{301}")), ("😀", None), ("e", None)] {
+        table.extend_from_slice(characters.as_bytes());
+        if let Some(sequence) = sequence {
+            table.push(0xfe);
+            table.extend_from_slice(sequence.as_bytes());
+        }
+        table.push(0xff);
+    }
+    let font = parse(psf2(4, &table)).unwrap();
+    assert_eq!(glyph_index(&font, 'é'), Some(0));
+    assert_eq!(glyph_index(&font, '€'), Some(1));
+    assert_eq!(glyph_index(&font, '😀'), Some(2));
+    assert_eq!(glyph_index(&font, 'e'), Some(3));
+    assert_eq!(glyph_index(&font, '\u{301}'), None);
+    assert_eq!(glyph_index(&font, 'A'), None);
+}
+
+#[test]
+fn psf2_without_table_maps_characters_to_glyph_indices() {
+    let font = parse(psf2(300, &[])).unwrap();
+    assert_eq!(font.cell_size(), (8, 4));
+    assert_eq!(glyph_index(&font, 'A'), Some(65));
+    assert_eq!(glyph_index(&font, '\u{12b}'), Some(299));
+    assert_eq!(glyph_index(&font, '\u{12c}'), None);
+}
+
+#[test]
+fn malformed_fonts_are_rejected() {
+    assert_eq!(parse(b"not a font".to_vec()).unwrap_err(), FontError::InvalidMagic);
+
+    assert_eq!(parse(vec![0x36, 0x04, 0x00]).unwrap_err(), FontError::Truncated);
+    let mut short = psf1(0x00, 8, &[]);
+    short.pop();
+    assert_eq!(parse(short).unwrap_err(), FontError::Truncated);
+    let mut short = psf1(0x01, 8, &[]);
+    short.truncate(4 + 256 * 8);
+    assert_eq!(parse(short).unwrap_err(), FontError::Truncated);
+    assert_eq!(parse(vec![0x36, 0x04, 0x00, 0]).unwrap_err(), FontError::Unsupported);
+
+    assert_eq!(parse(psf2(1, &[])[..31].to_vec()).unwrap_err(), FontError::Truncated);
+    let mut short = psf2(2, &[]);
+    short.pop();
+    assert_eq!(parse(short).unwrap_err(), FontError::Truncated);
+    assert_eq!(parse(psf2(0, &[])).unwrap_err(), FontError::Unsupported);
+    let mut huge = psf2(1, &[]);
+    huge[16..20].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(parse(huge).unwrap_err(), FontError::Truncated);
+    // 4 bytes per glyph cannot hold 5 rows of 8 pixels.
+    let mut tall = psf2(1, &[]);
+    tall[24..28].copy_from_slice(&5u32.to_le_bytes());
+    assert_eq!(parse(tall).unwrap_err(), FontError::Unsupported);
+    let mut overflowing = psf2(1, &[]);
+    overflowing[24..28].copy_from_slice(&u32::MAX.to_le_bytes());
+    overflowing[28..32].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(parse(overflowing).unwrap_err(), FontError::Unsupported);
+    // Glyphs would overlap the header.
+    let mut short_header = psf2(1, &[]);
+    short_header[8..12].copy_from_slice(&16u32.to_le_bytes());
+    assert_eq!(parse(short_header).unwrap_err(), FontError::Unsupported);
+}
+
+#[cfg(feature = "latin1")]
+#[test]
+fn latin1_glyphs_are_distinct() {
+    let pixels = |c: char| {
+        let glyph = Font::Builtin.glyph(c).unwrap();
+        (0..8).flat_map(|y| (0..8).map(move |x| (x, y))).map(|(x, y)| glyph.pixel(x, y)).collect::<Vec<_>>()
+    };
+    for a in '\u{a1}'..='\u{ff}' {
+        for b in ('\u{20}'..='\u{7e}').chain('\u{a1}'..a) {
+            assert!(pixels(a) != pixels(b), "{a:?} is drawn like {b:?}");
+        }
+    }
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use ::common::bootloader_api::info::PixelFormat;
+use common::{golden::assert_golden, manager};
+
+#[test]
+fn builtin_ascii() {
+    let mut display = manager(200, 60, PixelFormat::Bgr, 4);
+    for line in (0x20u8..=0x7e).collect::<Vec<_>>().chunks(20) {
+        display.print(std::str::from_utf8(line).unwrap());
+    }
+    assert_golden("builtin_ascii", &display);
+}
+
+#[test]
+fn ansi_colors() {
+    let mut display = manager(160, 40, PixelFormat::Rgb, 3);
+    display.print("\x1b[31mred \x1b[42mgreen\x1b[0m\n");
+    display.print("\x1b[38;5;208mpalette \x1b[38;2;10;120;250mtrue\x1b[0m\n");
+    display.print("erased\x1b[3D\x1b[K\x1b[4;14Hx");
+    assert_golden("ansi_colors", &display);
+}
+
+#[cfg(feature = "cp437")]
+#[test]
+fn box_drawing() {
+    let mut display = manager(80, 50, PixelFormat::Bgr, 4);
+    display.print("╔═╦═╗\n║░║▓║\n╠═╬═╣\n║é║ß║\n╚═╩═╝");
+    assert_golden("box_drawing", &display);
+}
+
+#[test]
+fn text_attributes() {
+    let mut display = manager(200, 30, PixelFormat::Bgr, 4);
+    display.print("\x1b[1mbold\x1b[0m \x1b[3mitalic\x1b[0m \x1b[7minverse\x1b[0m\n");
+    display.print("\x1b[4munderline\x1b[0m \x1b[9;31mstrike\x1b[0m\n");
+    display.print("\x1b[1;3;4;33mall\x1b[22;23;24m none");
+    assert_golden("text_attributes", &display);
+}
@@ -0,0 +1,161 @@
+mod common;
+
+use ::common::bootloader_api::info::PixelFormat;
+use common::{is_lit, manager};
+use display_text__processor_graphics::{Attributes, Cell, DisplayTextManager, Font};
+
+fn cells(count: usize) -> &'static mut [Cell] {
+    Box::leak(vec![Cell::BLANK; count].into_boxed_slice())
+}
+
+/// A 4x6 PSF2 font in which every glyph but the space is a solid block.
+fn block_font() -> Font {
+    let mut data = Vec::new();
+    for field in [0x864a_b572u32, 0, 32, 0, 128, 6, 6, 4] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    for c in 0..128u8 {
+        data.extend_from_slice(&[if c == b' ' { 0x00 } else { 0xF0 }; 6]);
+    }
+    Font::from_psf(Box::leak(data.into_boxed_slice())).unwrap()
+}
+
+fn lit_pixels(display: &DisplayTextManager) -> usize {
+    let info = display.frame_info();
+    (0..info.height)
+        .flat_map(|y| (0..info.width).map(move |x| (x, y)))
+        .filter(|(x, y)| is_lit(display, *x, *y))
+        .count()
+}
+
+#[test]
+fn printed_text_is_kept_in_cells() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    assert_eq!(display.cell((0, 0)), None);
+    assert!(display.set_text_buffer(cells(11)).is_err());
+    display.set_text_buffer(cells(12)).unwrap();
+    display.print("h\x1b[31;7mi");
+    assert_eq!(display.cell((0, 0)).unwrap().character, 'h');
+    let cell = display.cell((1, 0)).unwrap();
+    assert_eq!(cell, Cell::new('i', (170, 0, 0), (0, 0, 0), Attributes::INVERSE));
+    assert_eq!(cell.colors(), ((0, 0, 0), (170, 0, 0)));
+    assert_eq!(display.cell((2, 0)).unwrap().character, ' ');
+    assert_eq!(display.cell((4, 0)), None);
+}
+
+#[test]
+fn scrolling_and_erasing_move_cells() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.set_text_buffer(cells(12)).unwrap();
+    display.print("ab\ncd\nef\ngh");
+    assert_eq!(display.cell((0, 0)).unwrap().character, 'c');
+    assert_eq!(display.cell((1, 2)).unwrap().character, 'h');
+    display.print("\x1b[1;2H\x1b[K");
+    assert_eq!(display.cell((0, 0)).unwrap().character, 'c');
+    assert_eq!(display.cell((1, 0)).unwrap().character, ' ');
+}
+
+#[test]
+fn text_is_redrawn_from_cells() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.set_text_buffer(cells(12)).unwrap();
+    display.print("#X\n\x1b[7m ");
+    let drawn = display.framebuffer().to_vec();
+    display.fill((0, 0, 0));
+    display.redraw_text();
+    assert_eq!(display.framebuffer(), &drawn[..]);
+
+    display.set_cell((3, 2), Cell::new('+', (0, 255, 0), (0, 0, 0), Attributes::NONE)).unwrap();
+    assert!(is_lit(&display, 34, 24));
+    assert!(display.set_cell((4, 0), Cell::BLANK).is_err());
+}
+
+#[test]
+fn changing_the_font_redraws_the_text() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.set_text_buffer(cells(50)).unwrap();
+    display.print("ab\n c");
+    display.set_font(block_font());
+    // Only as many 4x6 rows as the 50 cells hold, 10 columns each.
+    assert_eq!(display.text_size(), (10, 5));
+    assert_eq!(lit_pixels(&display), 3 * 4 * 6);
+    assert!(is_lit(&display, 0, 0) && is_lit(&display, 7, 5) && is_lit(&display, 4, 6));
+    assert!(!is_lit(&display, 0, 6));
+    assert_eq!(display.cell((1, 1)).unwrap().character, 'c');
+}
+
+#[test]
+fn visible_text_is_read_back_as_lines() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    let mut text = String::new();
+    display.write_text(&mut text).unwrap();
+    assert_eq!(text, "");
+
+    display.set_text_buffer(cells(12)).unwrap();
+    display.print("ab\x1b[1mcd\n e\n\n");
+    display.write_text(&mut text).unwrap();
+    assert_eq!(text, " e\n\n\n");
+
+    display.print("\x1b[1;1Hwxyz\x1b[3;2Hq");
+    text.clear();
+    display.write_text_rect((1, 0), (2, 9), &mut text).unwrap();
+    assert_eq!(text, "xy\n\nq\n");
+    text.clear();
+    display.write_text_rect((4, 0), (1, 1), &mut text).unwrap();
+    assert_eq!(text, "\n");
+}
+
+fn screen_text(display: &DisplayTextManager) -> String {
+    let mut text = String::new();
+    display.write_text(&mut text).unwrap();
+    text
+}
+
+#[test]
+fn scrolled_off_rows_are_kept_in_the_scrollback() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.set_text_buffer(cells(12)).unwrap();
+    assert!(display.set_scrollback_buffer(cells(3)).is_err());
+    // Room for two lines of four cells.
+    display.set_scrollback_buffer(cells(11)).unwrap();
+    display.print("1\n2\x1b[32m2\n3\n4\n5");
+    assert_eq!(display.scrollback_len(), 2);
+    assert_eq!(screen_text(&display), "3\n4\n5\n");
+
+    let live = display.framebuffer().to_vec();
+    display.scroll_view_up(1);
+    assert_eq!(display.view_offset(), 1);
+    assert_eq!(screen_text(&display), "22\n3\n4\n");
+    let mut expected = manager(40, 30, PixelFormat::Bgr, 4);
+    expected.print("2\x1b[32m2\n3\n4");
+    assert!(display.framebuffer() == expected.framebuffer());
+    display.page_up();
+    assert_eq!(display.view_offset(), 2);
+    assert_eq!(screen_text(&display), "1\n22\n3\n");
+    assert_eq!(display.cell((0, 0)).unwrap().character, '3');
+
+    display.page_down();
+    assert_eq!(display.view_offset(), 0);
+    assert!(display.framebuffer() == &live[..]);
+
+    display.scroll_view_up(5);
+    display.print("6");
+    assert_eq!(display.view_offset(), 0);
+    assert_eq!(screen_text(&display), "3\n4\n56\n");
+}
+
+#[test]
+fn the_scrollback_keeps_the_newest_rows() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.set_text_buffer(cells(12)).unwrap();
+    display.set_scrollback_buffer(cells(8)).unwrap();
+    display.print("a\nb\nc\nd\ne\nf");
+    assert_eq!(display.scrollback_len(), 2);
+    display.scroll_to_bottom();
+    display.scroll_view_up(usize::MAX);
+    assert_eq!(screen_text(&display), "b\nc\nd\n");
+    display.scroll_view_down(1);
+    assert_eq!(screen_text(&display), "c\nd\ne\n");
+    display.set_font(block_font());
+    assert_eq!((display.scrollback_len(), display.view_offset()), (0, 0));
+}
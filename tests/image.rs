@@ -0,0 +1,288 @@
+mod common;
+
+use ::common::bootloader_api::info::PixelFormat;
+use common::{is_lit, manager};
+#[cfg(feature = "png")]
+use display_text__processor_graphics::Png;
+#[cfg(feature = "qoi")]
+use display_text__processor_graphics::Qoi;
+#[cfg(feature = "tga")]
+use display_text__processor_graphics::Tga;
+use display_text__processor_graphics::{Bmp, Image, ImageError};
+
+/// A BMP file with an info header; `extra` holds the masks or palette.
+fn bmp(width: i32, height: i32, bits_per_pixel: u16, compression: u32, extra: &[u8], pixels: &[u8]) -> Vec<u8> {
+    let pixels_offset = 14 + 40 + extra.len();
+    let mut data = Vec::new();
+    data.extend_from_slice(b"BM");
+    data.extend_from_slice(&((pixels_offset + pixels.len()) as u32).to_le_bytes());
+    data.extend_from_slice(&[0; 4]);
+    data.extend_from_slice(&(pixels_offset as u32).to_le_bytes());
+    data.extend_from_slice(&40u32.to_le_bytes());
+    data.extend_from_slice(&width.to_le_bytes());
+    data.extend_from_slice(&height.to_le_bytes());
+    data.extend_from_slice(&1u16.to_le_bytes());
+    data.extend_from_slice(&bits_per_pixel.to_le_bytes());
+    data.extend_from_slice(&compression.to_le_bytes());
+    data.extend_from_slice(&[0; 20]);
+    data.extend_from_slice(extra);
+    data.extend_from_slice(pixels);
+    data
+}
+
+type Color = (u8, u8, u8, u8);
+
+/// Decodes a BMP file into a grid of RGBA colors, `None` where nothing was decoded.
+fn decode(data: &[u8]) -> Vec<Vec<Option<Color>>> {
+    decode_image(&Bmp::parse(data).unwrap())
+}
+
+fn decode_image(image: &impl Image) -> Vec<Vec<Option<Color>>> {
+    let (width, height) = image.size();
+    let mut grid = vec![vec![None; width]; height];
+    image
+        .decode(|(x, y), color| grid[y][x] = Some((color.r, color.g, color.b, color.a)))
+        .unwrap();
+    grid
+}
+
+const BLACK: Color = (0, 0, 0, 255);
+const WHITE: Color = (255, 255, 255, 255);
+const RED: Color = (255, 0, 0, 255);
+/// Black, white, red and blue, as BGRA palette entries.
+const PALETTE: [u8; 16] = [0, 0, 0, 0, 255, 255, 255, 0, 0, 0, 255, 0, 255, 0, 0, 0];
+
+#[test]
+fn bottom_up_24_bit_rows_with_padding() {
+    // Two rows of two pixels, each padded from 6 to 8 bytes; the last row is on top.
+    let pixels = [0, 0, 255, 255, 255, 255, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0];
+    let grid = decode(&bmp(2, 2, 24, 0, &[], &pixels));
+    assert_eq!(grid[0], [Some((0, 0, 255, 255)), Some(BLACK)]);
+    assert_eq!(grid[1], [Some(RED), Some(WHITE)]);
+}
+
+#[test]
+fn top_down_palette_images() {
+    let grid = decode(&bmp(10, -1, 1, 0, &PALETTE[..8], &[0b1010_0000, 0b0100_0000, 0, 0]));
+    let lit: Vec<bool> = grid[0].iter().map(|color| *color == Some(WHITE)).collect();
+    assert_eq!(lit, [true, false, true, false, false, false, false, false, false, true]);
+
+    let grid = decode(&bmp(3, -1, 4, 0, &PALETTE, &[0x21, 0x30, 0, 0]));
+    assert_eq!(grid[0], [Some(RED), Some(WHITE), Some((0, 0, 255, 255))]);
+}
+
+#[test]
+fn bit_field_masks_with_alpha() {
+    let mut masks = Vec::new();
+    for mask in [0x0000_00FFu32, 0x0000_FF00, 0x00FF_0000, 0xFF00_0000] {
+        masks.extend_from_slice(&mask.to_le_bytes());
+    }
+    let grid = decode(&bmp(1, 1, 32, 6, &masks, &[10, 20, 30, 128]));
+    assert_eq!(grid[0][0], Some((10, 20, 30, 128)));
+
+    let grid = decode(&bmp(1, 1, 16, 0, &[], &[0xE0, 0x03, 0, 0]));
+    assert_eq!(grid[0][0], Some((0, 255, 0, 255)));
+}
+
+#[test]
+fn run_length_encoding() {
+    // RLE8: a run, end of line, a delta, an absolute run and end of bitmap.
+    let rle8 = [3, 2, 0, 0, 0, 2, 1, 0, 0, 3, 1, 3, 1, 0, 0, 1];
+    let grid = decode(&bmp(5, 2, 8, 1, &PALETTE, &rle8));
+    assert_eq!(grid[1], [Some(RED), Some(RED), Some(RED), None, None]);
+    assert_eq!(grid[0], [None, Some(WHITE), Some((0, 0, 255, 255)), Some(WHITE), None]);
+
+    // RLE4 alternates the two nibbles of a run.
+    let rle4 = [5, 0x12, 0, 1];
+    let grid = decode(&bmp(5, 1, 4, 2, &PALETTE, &rle4));
+    assert_eq!(grid[0], [Some(WHITE), Some(RED), Some(WHITE), Some(RED), Some(WHITE)]);
+}
+
+#[test]
+fn malformed_files_are_rejected() {
+    let file = bmp(2, 2, 24, 0, &[], &[0; 16]);
+    assert_eq!(Bmp::parse(&file[..file.len() - 1]).unwrap_err(), ImageError::Truncated);
+    assert_eq!(Bmp::parse(b"GIF89a").unwrap_err(), ImageError::InvalidMagic);
+    assert_eq!(Bmp::parse(&bmp(2, 2, 2, 0, &[], &[0; 8])).unwrap_err(), ImageError::Unsupported);
+    assert_eq!(Bmp::parse(&bmp(2, -2, 8, 1, &PALETTE, &[0, 1])).unwrap_err(), ImageError::Unsupported);
+    let file = bmp(4, 1, 8, 1, &PALETTE, &[0, 5, 1]);
+    let image = Bmp::parse(&file).unwrap();
+    assert_eq!(image.decode(|_, _| {}).unwrap_err(), ImageError::Truncated);
+}
+
+#[test]
+fn blit_clips_and_converts() {
+    let mut pixels = Vec::new();
+    for _ in 0..4 {
+        pixels.extend_from_slice(&[0, 0, 255, 0, 0, 255, 0, 0, 255, 0, 0, 255]);
+    }
+    let file = bmp(4, 4, 24, 0, &[], &pixels);
+    let image = Bmp::parse(&file).unwrap();
+    let mut display = manager(4, 4, PixelFormat::Rgb, 2);
+    display.blit(&image, (-2, 2)).unwrap();
+    assert_eq!(display.get_pixel((1, 3)).unwrap(), (255, 0, 0));
+    assert!(!is_lit(&display, 2, 2) && !is_lit(&display, 1, 1));
+    assert_eq!(display.damage().len(), 1);
+}
+
+#[cfg(feature = "qoi")]
+#[test]
+fn qoi_operations() {
+    let mut data = b"qoif".to_vec();
+    data.extend_from_slice(&3u32.to_be_bytes());
+    data.extend_from_slice(&2u32.to_be_bytes());
+    data.extend_from_slice(&[4, 0]);
+    // RGB, a run of one more, DIFF (-1, 0, 0), LUMA (+10 all round), INDEX of
+    // the first color, RGBA, and the end marker.
+    data.extend_from_slice(&[0xFE, 255, 0, 0, 0xC0, 0x5A, 0xAA, 0x88, 0x32, 0xFF, 0, 0, 255, 128]);
+    data.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 1]);
+    let grid = decode_image(&Qoi::parse(&data).unwrap());
+    assert_eq!(grid[0], [Some(RED), Some(RED), Some((254, 0, 0, 255))]);
+    assert_eq!(grid[1], [Some((8, 10, 10, 255)), Some(RED), Some((0, 0, 255, 128))]);
+
+    assert_eq!(Qoi::parse(b"qoif").unwrap_err(), ImageError::Truncated);
+    assert_eq!(Qoi::parse(&data[..2]).unwrap_err(), ImageError::InvalidMagic);
+    let truncated = Qoi::parse(&data[..18]).unwrap();
+    assert_eq!(truncated.decode(|_, _| {}).unwrap_err(), ImageError::Truncated);
+}
+
+/// A TGA file; `color_map` is the entry size in bits and the entries.
+#[cfg(feature = "tga")]
+fn tga(
+    image_type: u8,
+    bits: u8,
+    descriptor: u8,
+    size: (u16, u16),
+    color_map: Option<(u8, &[u8])>,
+    pixels: &[u8],
+) -> Vec<u8> {
+    let (entry_bits, entries) = color_map.unwrap_or((0, &[]));
+    let entry_count = if entry_bits == 0 { 0 } else { entries.len() / (entry_bits as usize / 8) };
+    let mut data = vec![0, color_map.is_some() as u8, image_type, 0, 0];
+    data.extend_from_slice(&(entry_count as u16).to_le_bytes());
+    data.extend_from_slice(&[entry_bits, 0, 0, 0, 0]);
+    data.extend_from_slice(&size.0.to_le_bytes());
+    data.extend_from_slice(&size.1.to_le_bytes());
+    data.extend_from_slice(&[bits, descriptor]);
+    data.extend_from_slice(entries);
+    data.extend_from_slice(pixels);
+    data
+}
+
+#[cfg(feature = "tga")]
+#[test]
+fn tga_pixel_orders_and_types() {
+    // Truecolor, bottom up.
+    let data = tga(2, 24, 0, (2, 2), None, &[0, 0, 255, 255, 255, 255, 0, 0, 0, 255, 0, 0]);
+    let grid = decode_image(&Tga::parse(&data).unwrap());
+    assert_eq!(grid, [[Some(BLACK), Some((0, 0, 255, 255))], [Some(RED), Some(WHITE)]]);
+
+    // Color-mapped, right to left and top down.
+    let map = [0, 0, 0, 255, 255, 255, 0, 0, 255];
+    let data = tga(1, 8, 0x30, (3, 1), Some((24, &map)), &[0, 1, 2]);
+    let grid = decode_image(&Tga::parse(&data).unwrap());
+    assert_eq!(grid[0], [Some(RED), Some(WHITE), Some(BLACK)]);
+
+    // Grayscale with alpha.
+    let data = tga(3, 16, 0x28, (1, 1), None, &[100, 50]);
+    assert_eq!(decode_image(&Tga::parse(&data).unwrap())[0][0], Some((100, 100, 100, 50)));
+}
+
+#[cfg(feature = "tga")]
+#[test]
+fn tga_run_length_encoding() {
+    // A run of three translucent red pixels crossing into the second row,
+    // then a raw packet with one white pixel.
+    let pixels = [0x82, 0, 0, 255, 128, 0x00, 255, 255, 255, 255];
+    let data = tga(10, 32, 0x28, (2, 2), None, &pixels);
+    let grid = decode_image(&Tga::parse(&data).unwrap());
+    let translucent = Some((255, 0, 0, 128));
+    assert_eq!(grid, [[translucent, translucent], [translucent, Some(WHITE)]]);
+
+    let data = tga(10, 32, 0x28, (2, 2), None, &pixels[..7]);
+    assert_eq!(Tga::parse(&data).unwrap().decode(|_, _| {}).unwrap_err(), ImageError::Truncated);
+    let data = tga(10, 32, 0x28, (1, 2), None, &pixels);
+    assert_eq!(Tga::parse(&data).unwrap().decode(|_, _| {}).unwrap_err(), ImageError::Corrupt);
+    assert_eq!(Tga::parse(&tga(2, 7, 0, (1, 1), None, &[0])).unwrap_err(), ImageError::Unsupported);
+}
+
+/// Decodes a PNG fixture from `tests/images`, and checks every pixel against `expected`.
+#[cfg(feature = "png")]
+fn assert_png(data: &[u8], expected: impl Fn(usize, usize) -> Color) {
+    let mut scratch = vec![0; Png::scratch_size(data).unwrap()];
+    let grid = decode_image(&Png::parse(data, &mut scratch).unwrap());
+    for (y, row) in grid.iter().enumerate() {
+        for (x, color) in row.iter().enumerate() {
+            assert_eq!(*color, Some(expected(x, y)), "pixel ({x}, {y})");
+        }
+    }
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn png_color_types_and_bit_depths() {
+    // Every filter type, a dynamic Huffman code, split across several IDAT chunks.
+    assert_png(include_bytes!("images/rgba8.png"), |x, y| {
+        ((x * 16) as u8, (y * 20) as u8, (x * y * 3 % 256) as u8, (x * 17 % 256) as u8)
+    });
+    assert_png(include_bytes!("images/gray1.png"), |x, y| {
+        let gray = if (x + y) % 2 == 1 { 255 } else { 0 };
+        (gray, gray, gray, 255)
+    });
+    // Stored blocks, a palette and partial palette transparency.
+    assert_png(include_bytes!("images/indexed4_stored.png"), |x, y| {
+        let index = (x + y * 3) % 16;
+        let alpha = [0, 128, 200].get(index).copied().unwrap_or(255);
+        ((index * 16) as u8, (255 - index * 16) as u8, (index * 8) as u8, alpha)
+    });
+    // A transparent color key, compared at full 16-bit precision.
+    assert_png(include_bytes!("images/rgb16.png"), |x, y| {
+        let alpha = if (x, y) == (0, 1) { 0 } else { 255 };
+        (((x * 20000) >> 8) as u8, ((y * 20000) >> 8) as u8, (1000 >> 8) as u8, alpha)
+    });
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn png_interlacing() {
+    assert_png(include_bytes!("images/gray16_adam7.png"), |x, y| {
+        let gray = ((x * 5000 + y * 100) >> 8) as u8;
+        (gray, gray, gray, if (x, y) == (3, 4) { 0 } else { 255 })
+    });
+    assert_png(include_bytes!("images/gray_alpha8_adam7.png"), |x, y| {
+        let gray = (x * 50) as u8;
+        (gray, gray, gray, (y * 80) as u8)
+    });
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn png_blends_over_the_screen() {
+    let data = include_bytes!("images/gray_alpha8_adam7.png");
+    let mut scratch = vec![0; Png::scratch_size(data).unwrap()];
+    let image = Png::parse(data, &mut scratch).unwrap();
+    let mut display = manager(8, 8, PixelFormat::Bgr, 4);
+    display.fill_rect((0, 0), (8, 8), (0, 0, 200));
+    display.blit(&image, (1, 1)).unwrap();
+    // Fully transparent, partly and nearly opaque pixels of gray over blue.
+    assert_eq!(display.get_pixel((3, 1)).unwrap(), (0, 0, 200));
+    assert_eq!(display.get_pixel((3, 3)).unwrap(), (63, 63, 137));
+    assert_eq!(display.get_pixel((5, 4)).unwrap(), (188, 188, 200));
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn malformed_pngs_are_rejected() {
+    let data = include_bytes!("images/rgba8.png");
+    let mut scratch = vec![0; Png::scratch_size(data).unwrap()];
+    assert_eq!(Png::parse(&data[1..], &mut scratch).unwrap_err(), ImageError::InvalidMagic);
+    assert_eq!(Png::parse(&data[..100], &mut scratch).unwrap_err(), ImageError::Truncated);
+    let len = scratch.len();
+    assert_eq!(Png::parse(data, &mut scratch[..len - 1]).unwrap_err(), ImageError::ScratchTooSmall);
+
+    // Flipping a bit in the compressed data breaks the stream or its checksum.
+    let mut corrupt = data.to_vec();
+    corrupt[120] ^= 0x10;
+    let image = Png::parse(&corrupt, &mut scratch).unwrap();
+    assert!(image.decode(|_, _| {}).is_err());
+}
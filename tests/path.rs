@@ -0,0 +1,99 @@
+mod common;
+
+use ::common::bootloader_api::info::PixelFormat;
+use common::{golden::assert_golden, is_lit, manager};
+use display_text__processor_graphics::{FillRule, Path};
+
+const WHITE: (u8, u8, u8) = (255, 255, 255);
+
+/// A five-pointed star drawn in one stroke, so its center is wound twice.
+const STAR: [(f32, f32); 5] = [(20.0, 2.0), (31.0, 36.0), (2.0, 15.0), (38.0, 15.0), (9.0, 36.0)];
+
+#[test]
+fn fill_rules_differ_on_self_intersections() {
+    let mut display = manager(40, 40, PixelFormat::Bgr, 4);
+    display.fill_polygon(&STAR, FillRule::EvenOdd, WHITE);
+    assert!(is_lit(&display, 20, 8) && !is_lit(&display, 20, 20));
+
+    let mut display = manager(40, 40, PixelFormat::Bgr, 4);
+    display.fill_polygon(&STAR, FillRule::NonZero, WHITE);
+    assert!(is_lit(&display, 20, 8) && is_lit(&display, 20, 20));
+}
+
+#[test]
+fn polygon_fills_pixels_whose_centers_are_inside() {
+    let mut display = manager(10, 10, PixelFormat::Bgr, 4);
+    display.fill_polygon(&[(2.0, 2.0), (6.0, 2.0), (6.0, 5.0), (2.0, 5.0)], FillRule::NonZero, WHITE);
+    let lit = (0..10).flat_map(|y| (0..10).map(move |x| (x, y))).filter(|(x, y)| is_lit(&display, *x, *y));
+    assert!(lit.clone().all(|(x, y)| (2..6).contains(&x) && (2..5).contains(&y)));
+    assert_eq!(lit.count(), 12);
+}
+
+#[test]
+fn contours_of_a_path_can_cut_holes() {
+    let mut storage = [(0.0, 0.0); 16];
+    let mut path = Path::new(&mut storage, 0.5);
+    for (x, y, size) in [(1.0, 1.0, 18.0), (6.0, 6.0, 8.0)] {
+        path.move_to((x, y)).unwrap();
+        path.line_to((x + size, y)).unwrap();
+        path.line_to((x + size, y + size)).unwrap();
+        path.line_to((x, y + size)).unwrap();
+        path.close();
+    }
+    let mut display = manager(20, 20, PixelFormat::Bgr, 4);
+    display.fill_path(&path, FillRule::EvenOdd, WHITE);
+    assert!(is_lit(&display, 3, 3) && !is_lit(&display, 10, 10));
+}
+
+#[test]
+fn curves_are_flattened_within_tolerance() {
+    let mut storage = [(0.0, 0.0); 300];
+    let mut path = Path::new(&mut storage, 0.1);
+    path.move_to((0.0, 0.0)).unwrap();
+    path.quad_to((50.0, 100.0), (100.0, 0.0)).unwrap();
+    let points = path.points();
+    assert!(points.len() > 10);
+    assert_eq!(points.last(), Some(&(100.0, 0.0)));
+    for point in points {
+        // The curve is the parabola y = 2x - x² / 50.
+        let expected = 2.0 * point.0 - point.0 * point.0 / 50.0;
+        assert!((point.1 - expected).abs() < 0.01);
+    }
+
+    let mut coarse_storage = [(0.0, 0.0); 300];
+    let mut coarse = Path::new(&mut coarse_storage, 4.0);
+    coarse.move_to((0.0, 0.0)).unwrap();
+    coarse.quad_to((50.0, 100.0), (100.0, 0.0)).unwrap();
+    assert!(coarse.points().len() < points.len());
+}
+
+#[test]
+fn path_reports_full_storage() {
+    let mut storage = [(0.0, 0.0); 3];
+    let mut path = Path::new(&mut storage, 0.5);
+    path.move_to((0.0, 0.0)).unwrap();
+    path.line_to((1.0, 0.0)).unwrap();
+    path.line_to((1.0, 1.0)).unwrap();
+    assert!(path.line_to((0.0, 1.0)).is_err());
+    assert!(path.cubic_to((0.0, 5.0), (5.0, 5.0), (5.0, 0.0)).is_err());
+}
+
+#[test]
+fn paths() {
+    let mut display = manager(120, 80, PixelFormat::Bgr, 4);
+    display.fill_polygon(&STAR.map(|(x, y)| (x + 2.0, y + 2.0)), FillRule::EvenOdd, (255, 200, 0));
+    display.fill_polygon(&STAR.map(|(x, y)| (x + 2.0, y + 42.0)), FillRule::NonZero, (255, 120, 0));
+
+    let mut storage = [(0.0, 0.0); 256];
+    let mut path = Path::new(&mut storage, 0.25);
+    path.move_to((50.0, 40.0)).unwrap();
+    path.cubic_to((50.0, 5.0), (110.0, 5.0), (110.0, 40.0)).unwrap();
+    path.cubic_to((110.0, 75.0), (50.0, 75.0), (50.0, 40.0)).unwrap();
+    path.close();
+    path.move_to((65.0, 40.0)).unwrap();
+    path.quad_to((80.0, 15.0), (95.0, 40.0)).unwrap();
+    path.quad_to((80.0, 65.0), (65.0, 40.0)).unwrap();
+    path.close();
+    display.fill_path(&path, FillRule::EvenOdd, (80, 160, 255));
+    assert_golden("paths", &display);
+}
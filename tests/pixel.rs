@@ -0,0 +1,94 @@
+mod common;
+
+use ::common::bootloader_api::info::PixelFormat;
+use common::{manager, manager_with_stride, pixel};
+
+const COLOR: (u8, u8, u8) = (0x12, 0x34, 0x56);
+
+#[test]
+fn set_pixel_rejects_positions_outside_the_screen() {
+    let mut display = manager(4, 3, PixelFormat::Bgr, 4);
+    assert!(display.set_pixel((3, 2), COLOR).is_ok());
+    assert!(display.set_pixel((4, 0), COLOR).is_err());
+    assert!(display.set_pixel((0, 3), COLOR).is_err());
+    assert_eq!(display.framebuffer().iter().filter(|byte| **byte != 0).count(), 3);
+}
+
+#[test]
+fn set_pixel_respects_stride() {
+    let mut display = manager_with_stride(4, 3, 6, PixelFormat::Bgr, 4);
+    display.set_pixel((1, 2), COLOR).unwrap();
+    assert_eq!(&display.framebuffer()[(2 * 6 + 1) * 4..][..4], &[0x56, 0x34, 0x12, 0]);
+}
+
+#[test]
+fn bgr_byte_order() {
+    let mut display = manager(2, 2, PixelFormat::Bgr, 4);
+    display.set_pixel((1, 1), COLOR).unwrap();
+    assert_eq!(pixel(&display, 1, 1), &[0x56, 0x34, 0x12, 0]);
+
+    let mut display = manager(2, 2, PixelFormat::Bgr, 3);
+    display.set_pixel((1, 1), COLOR).unwrap();
+    assert_eq!(pixel(&display, 1, 1), &[0x56, 0x34, 0x12]);
+}
+
+#[test]
+fn rgb_byte_order() {
+    let mut display = manager(2, 2, PixelFormat::Rgb, 4);
+    display.set_pixel((1, 0), COLOR).unwrap();
+    assert_eq!(pixel(&display, 1, 0), &[0x12, 0x34, 0x56, 0]);
+
+    let mut display = manager(2, 2, PixelFormat::Rgb, 3);
+    display.set_pixel((1, 0), COLOR).unwrap();
+    assert_eq!(pixel(&display, 1, 0), &[0x12, 0x34, 0x56]);
+}
+
+#[test]
+fn sixteen_and_eight_bit_color() {
+    let mut display = manager(2, 2, PixelFormat::Bgr, 2);
+    display.set_pixel((0, 0), (0xff, 0x00, 0x00)).unwrap();
+    display.set_pixel((1, 0), (0x00, 0xff, 0x00)).unwrap();
+    assert_eq!(pixel(&display, 0, 0), &0xf800u16.to_le_bytes());
+    assert_eq!(pixel(&display, 1, 0), &0x07e0u16.to_le_bytes());
+
+    let mut display = manager(2, 2, PixelFormat::Rgb, 1);
+    display.set_pixel((0, 0), (0xff, 0x00, 0xff)).unwrap();
+    assert_eq!(pixel(&display, 0, 0), &[0b1100_0111]);
+}
+
+#[test]
+fn grayscale() {
+    let mut display = manager(2, 2, PixelFormat::U8, 1);
+    display.set_pixel((0, 0), (255, 255, 255)).unwrap();
+    display.set_pixel((1, 0), (0, 255, 0)).unwrap();
+    assert_eq!(pixel(&display, 0, 0), &[255]);
+    assert_eq!(pixel(&display, 1, 0), &[149]);
+}
+
+#[test]
+fn unknown_format_uses_reported_positions() {
+    let format = PixelFormat::Unknown {
+        red_position: 8,
+        green_position: 16,
+        blue_position: 24,
+    };
+    let mut display = manager(2, 2, format, 4);
+    display.set_pixel((0, 1), COLOR).unwrap();
+    assert_eq!(pixel(&display, 0, 1), &[0, 0x12, 0x34, 0x56]);
+}
+
+#[test]
+fn get_pixel_reads_back_through_the_pixel_format() {
+    for (format, bytes_per_pixel, expected) in [
+        (PixelFormat::Bgr, 4, COLOR),
+        (PixelFormat::Rgb, 3, COLOR),
+        (PixelFormat::Bgr, 2, (0x10, 0x34, 0x52)),
+        (PixelFormat::U8, 1, (0x2d, 0x2d, 0x2d)),
+    ] {
+        let mut display = manager(4, 3, format, bytes_per_pixel);
+        display.set_pixel((1, 2), COLOR).unwrap();
+        assert_eq!(display.get_pixel((1, 2)).unwrap(), expected);
+        assert_eq!(display.get_pixel((0, 0)).unwrap(), (0, 0, 0));
+        assert!(display.get_pixel((4, 0)).is_err());
+    }
+}
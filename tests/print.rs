@@ -0,0 +1,229 @@
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ::common::bootloader_api::info::PixelFormat;
+use common::{is_lit, manager};
+
+use display_text__processor_graphics::{Attributes, Cell, Font};
+
+#[test]
+fn print_wraps_at_the_end_of_the_line() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.print("abcde");
+    assert_eq!(display.cursor(), (1, 1));
+    display.print("\nx");
+    assert_eq!(display.cursor(), (1, 2));
+}
+
+fn row_is_lit(display: &display_text__processor_graphics::DisplayTextManager, row: usize) -> bool {
+    (0..10).any(|y| (0..40).any(|x| is_lit(display, x, row * 10 + y)))
+}
+
+#[test]
+fn a_full_line_wraps_only_before_the_next_character() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.print("abcd");
+    assert_eq!(display.cursor(), (3, 0));
+    display.print("\rx");
+    assert_eq!(display.cursor(), (1, 0));
+    display.print("\x1b[Kabc\x08");
+    assert_eq!(display.cursor(), (2, 0));
+
+    // The bottom row fills up without scrolling.
+    display.print("\n\nwxyz");
+    assert_eq!(display.cursor(), (3, 2));
+    assert!(row_is_lit(&display, 0));
+    display.print("!");
+    assert_eq!(display.cursor(), (1, 2));
+    assert!(!row_is_lit(&display, 0));
+}
+
+#[test]
+fn print_scrolls_when_reaching_the_bottom() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.print("|\n\n");
+    assert!(is_lit(&display, 3, 0));
+    display.print("\n");
+    assert_eq!(display.cursor(), (0, 2));
+    assert!(!is_lit(&display, 3, 0));
+    assert!((0..10).all(|y| !is_lit(&display, 3, 20 + y)));
+}
+
+#[test]
+fn glyphs_are_drawn_in_the_foreground_color() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.print(" A");
+    let glyph = Font::Builtin.glyph('A').unwrap();
+    for y in 0..10 {
+        for x in 0..10 {
+            assert_eq!(is_lit(&display, 10 + x, y), glyph.pixel(x, y), "pixel ({x}, {y})");
+            assert!(!is_lit(&display, x, y));
+        }
+    }
+}
+
+/// The lit pixels of the 10x10 cell at `column` of the first row.
+fn cell_pixels(display: &display_text__processor_graphics::DisplayTextManager, column: usize) -> [[bool; 10]; 10] {
+    let mut pixels = [[false; 10]; 10];
+    for (y, row) in pixels.iter_mut().enumerate() {
+        for (x, pixel) in row.iter_mut().enumerate() {
+            *pixel = is_lit(display, column * 10 + x, y);
+        }
+    }
+    pixels
+}
+
+#[test]
+fn attributes_change_how_glyphs_are_drawn() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.print("H\x1b[1mH\x1b[22;3mH\x1b[23;4;9m ");
+    let (plain, bold, italic, ruled) = (
+        cell_pixels(&display, 0),
+        cell_pixels(&display, 1),
+        cell_pixels(&display, 2),
+        cell_pixels(&display, 3),
+    );
+    for y in 0..10 {
+        let shift = (7usize.saturating_sub(y) + 2) / 4;
+        for x in 0..10usize {
+            let left = x.checked_sub(1).is_some_and(|x| plain[y][x]);
+            assert_eq!(bold[y][x], plain[y][x] || left, "bold pixel ({x}, {y})");
+            let source = x.checked_sub(shift).is_some_and(|x| plain[y][x]);
+            assert_eq!(italic[y][x], source, "italic pixel ({x}, {y})");
+            assert_eq!(ruled[y][x], y == 4 || y == 7, "ruled pixel ({x}, {y})");
+        }
+    }
+
+    display.print("\x1b[H\x1b[1;3;4;9mH\x1b[22;23;24;29mH");
+    assert_eq!(cell_pixels(&display, 1), plain);
+}
+
+#[test]
+fn tabs_move_to_the_next_tab_stop() {
+    let mut display = manager(200, 30, PixelFormat::Bgr, 4);
+    display.print("\t");
+    assert_eq!(display.cursor(), (8, 0));
+    display.print("abc\t");
+    assert_eq!(display.cursor(), (16, 0));
+    // Past the last stop, tabs go to the last column without wrapping.
+    display.print("\t\t");
+    assert_eq!(display.cursor(), (19, 0));
+    let drawn = |column: usize| (0..100).any(|i| is_lit(&display, column * 10 + i % 10, i / 10));
+    assert!(!drawn(0) && drawn(8) && drawn(10) && !drawn(11));
+
+    display.set_tab_stop(3);
+    display.clear_tab_stop(8);
+    display.print("\r\t");
+    assert_eq!(display.cursor(), (3, 0));
+    display.print("\t");
+    assert_eq!(display.cursor(), (16, 0));
+
+    // `ESC H` sets a stop at the cursor, `ESC[g` clears it and `ESC[3g` clears all.
+    display.print("\r\x1b[5C\x1bH\r\t\t");
+    assert_eq!(display.cursor(), (5, 0));
+    display.print("\x1b[g\r\t\t");
+    assert_eq!(display.cursor(), (16, 0));
+    display.print("\x1b[3g\r\t");
+    assert_eq!(display.cursor(), (19, 0));
+    display.reset_tab_stops();
+    display.print("\r\t");
+    assert_eq!(display.cursor(), (8, 0));
+    display.clear_tab_stops();
+    display.print("\r\t");
+    assert_eq!(display.cursor(), (19, 0));
+}
+
+#[test]
+fn backspace_moves_back_and_may_erase() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.print("|\x08");
+    assert_eq!(display.cursor(), (0, 0));
+    display.print("\x08");
+    assert_eq!(display.cursor(), (0, 0));
+    assert!(is_lit(&display, 3, 0));
+
+    display.set_destructive_backspace(true);
+    display.print("\r\n||\x08");
+    assert_eq!(display.cursor(), (1, 1));
+    assert!(is_lit(&display, 3, 10) && !is_lit(&display, 13, 10));
+}
+
+#[test]
+fn other_control_characters_are_not_drawn() {
+    static RINGS: AtomicUsize = AtomicUsize::new(0);
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.print("\x07\x00\x7f");
+    assert_eq!(display.cursor(), (0, 0));
+    assert!(display.framebuffer().iter().all(|byte| *byte == 0));
+
+    display.set_bell_handler(Some(|| {
+        RINGS.fetch_add(1, Ordering::Relaxed);
+    }));
+    display.print("|\x07\x07");
+    assert_eq!(RINGS.load(Ordering::Relaxed), 2);
+
+    // Form feed clears the screen and moves the cursor home.
+    display.print("\n|\x0c");
+    assert_eq!(display.cursor(), (0, 0));
+    assert!(display.framebuffer().iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn restored_cursor_is_clamped_to_the_text_grid() {
+    let mut data = Vec::new();
+    for field in [0x864a_b572u32, 0, 32, 0, 128, 6, 6, 4] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    data.resize(32 + 128 * 6, 0xF0);
+    let small = Font::from_psf(Box::leak(data.into_boxed_slice())).unwrap();
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.set_font(small);
+    display.print("\x1b[5;10H\x1b7\x1b[s");
+    assert_eq!(display.cursor(), (9, 4));
+    display.set_font(Font::Builtin);
+    display.print("\x1b8");
+    assert_eq!(display.cursor(), (3, 2));
+    display.print("\x1b[H\x1b[u");
+    assert_eq!(display.cursor(), (3, 2));
+    display.print("x");
+    assert!((0..30).any(|y| (30..40).any(|x| is_lit(&display, x, y))));
+}
+
+#[test]
+fn escapes_with_intermediate_bytes_are_ignored() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.print("a\x1b7\x1b[H");
+    // DECALN, not DECRC.
+    display.print("\x1b#8");
+    assert_eq!(display.cursor(), (0, 0));
+    display.print("\x1b(Bb");
+    assert_eq!(display.cursor(), (1, 0));
+}
+
+#[test]
+fn parameters_past_the_sixteenth_are_dropped() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.set_text_buffer(Box::leak(vec![Cell::BLANK; 12].into_boxed_slice())).unwrap();
+    // Fifteen bolds, then italic, then a 1 that must not run into the 3.
+    display.print(&format!("\x1b[{}3;1mx", "1;".repeat(15)));
+    assert_eq!(
+        display.cell((0, 0)),
+        Some(Cell::new('x', (255, 255, 255), (0, 0, 0), Attributes::BOLD | Attributes::ITALIC))
+    );
+    display.print("\x1b[0m\x1b[1;2;3;4;5;6;7;8;9;10;11;12;13;14;15;16;31;17my");
+    assert_eq!(display.cell((1, 0)).unwrap().foreground, (255, 255, 255));
+}
+
+#[test]
+fn controls_inside_a_sequence_take_effect() {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.set_text_buffer(Box::leak(vec![Cell::BLANK; 12].into_boxed_slice())).unwrap();
+    display.print("ab\x1b[3\r1mx");
+    assert_eq!(display.cursor(), (1, 0));
+    assert_eq!(display.cell((0, 0)).unwrap().foreground, (170, 0, 0));
+    // CAN cancels the sequence.
+    display.print("\x1b[0;32\x18m");
+    assert_eq!(display.cursor(), (2, 0));
+    assert_eq!(display.cell((1, 0)).unwrap().foreground, (170, 0, 0));
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use core::fmt;
+
+use ::common::bootloader_api::info::PixelFormat;
+use common::manager;
+use display_text__processor_graphics::{Bmp, ByteSink, Image, Rect, ScreenshotFormat};
+
+struct Bytes(Vec<u8>);
+
+impl ByteSink for Bytes {
+    fn write_bytes(&mut self, bytes: &[u8]) -> fmt::Result {
+        self.0.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// Accepts `limit` bytes, then fails.
+struct Limited(usize);
+
+impl ByteSink for Limited {
+    fn write_bytes(&mut self, bytes: &[u8]) -> fmt::Result {
+        self.0 = self.0.checked_sub(bytes.len()).ok_or(fmt::Error)?;
+        Ok(())
+    }
+}
+
+#[test]
+fn ppm_is_normalized_to_rgb() {
+    for (format, bytes_per_pixel) in [(PixelFormat::Bgr, 4), (PixelFormat::Rgb, 3)] {
+        let mut display = manager(3, 2, format, bytes_per_pixel);
+        display.set_pixel((0, 0), (10, 20, 30)).unwrap();
+        display.set_pixel((2, 1), (255, 0, 128)).unwrap();
+        let mut out = Bytes(Vec::new());
+        display.screenshot(ScreenshotFormat::Ppm, &mut out).unwrap();
+        let mut expected = b"P6\n3 2\n255\n".to_vec();
+        expected.extend_from_slice(&[10, 20, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 128]);
+        assert_eq!(out.0, expected);
+    }
+}
+
+#[test]
+fn bmp_of_a_rectangle_round_trips() {
+    let mut display = manager(40, 30, PixelFormat::Rgb, 2);
+    for y in 0..30 {
+        for x in 0..40 {
+            display.set_pixel((x, y), ((x * 6) as u8, (y * 8) as u8, 255)).unwrap();
+        }
+    }
+    let mut out = Bytes(Vec::new());
+    let area = Rect::new((35, 27), (10, 10));
+    display.screenshot_rect(area, ScreenshotFormat::Bmp, &mut out).unwrap();
+    // A 5x3 image, with rows of 15 bytes padded to 16.
+    assert_eq!(out.0.len(), 54 + 16 * 3);
+    let image = Bmp::parse(&out.0).unwrap();
+    assert_eq!(image.size(), (5, 3));
+    image
+        .decode(|(x, y), color| {
+            assert_eq!((color.r, color.g, color.b), display.get_pixel((35 + x, 27 + y)).unwrap());
+        })
+        .unwrap();
+}
+
+#[test]
+fn screenshot_shows_what_was_flushed() {
+    let mut display = manager(2, 1, PixelFormat::Bgr, 4);
+    display.set_back_buffer(Box::leak(vec![0; 8].into_boxed_slice())).unwrap();
+    display.set_pixel((0, 0), (255, 255, 255)).unwrap();
+    let mut out = Bytes(Vec::new());
+    display.screenshot(ScreenshotFormat::Ppm, &mut out).unwrap();
+    assert!(out.0.ends_with(&[0; 6]));
+    display.flush();
+    out.0.clear();
+    display.screenshot(ScreenshotFormat::Ppm, &mut out).unwrap();
+    assert!(out.0.ends_with(&[255, 255, 255, 0, 0, 0]));
+}
+
+#[test]
+fn sink_errors_stop_the_screenshot() {
+    let display = manager(100, 100, PixelFormat::Bgr, 4);
+    assert!(display.screenshot(ScreenshotFormat::Bmp, &mut Limited(1000)).is_err());
+    assert!(display.screenshot(ScreenshotFormat::Ppm, &mut Limited(3)).is_err());
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use ::common::bootloader_api::info::PixelFormat;
+use common::{manager, pixel};
+use display_text__processor_graphics::{Cell, DisplayTextManager, MAX_PATTERN_LEN, SearchMatch};
+
+/// A 4x3 text grid with a text buffer and eight lines of scrollback.
+fn console() -> DisplayTextManager {
+    let mut display = manager(40, 30, PixelFormat::Bgr, 4);
+    display.set_text_buffer(Box::leak(vec![Cell::BLANK; 12].into_boxed_slice())).unwrap();
+    display.set_scrollback_buffer(Box::leak(vec![Cell::BLANK; 32].into_boxed_slice())).unwrap();
+    display
+}
+
+fn screen_text(display: &DisplayTextManager) -> String {
+    let mut text = String::new();
+    display.write_text(&mut text).unwrap();
+    text
+}
+
+#[test]
+fn matches_are_found_in_both_directions() {
+    let mut display = console();
+    display.print("err\nok\nERR\nok\nok\nxerr\n");
+    display.search("err", false, false).unwrap();
+    assert_eq!(display.find_next(), Some(SearchMatch { line: 5, column: 1 }));
+    assert_eq!(display.find_next(), None);
+    assert_eq!(display.find_previous(), Some(SearchMatch { line: 0, column: 0 }));
+    assert_eq!(display.view_offset(), 4);
+    assert_eq!(screen_text(&display), "err\nok\nERR\n");
+    assert_eq!(display.find_previous(), None);
+
+    display.search("e?r", true, true).unwrap();
+    assert_eq!(display.find_next(), Some(SearchMatch { line: 0, column: 0 }));
+    assert_eq!(display.find_next(), Some(SearchMatch { line: 2, column: 0 }));
+    assert_eq!(display.find_next(), Some(SearchMatch { line: 5, column: 1 }));
+    // The view scrolls only as far as needed to show the match.
+    assert_eq!(display.view_offset(), 1);
+    assert_eq!(screen_text(&display), "ok\nok\nxerr\n");
+}
+
+#[test]
+fn question_marks_are_literal_without_wildcards() {
+    let mut display = console();
+    display.print("ok?\nab\nwh?");
+    display.search("?", false, false).unwrap();
+    assert_eq!(display.find_next(), Some(SearchMatch { line: 0, column: 2 }));
+    assert_eq!(display.find_next(), Some(SearchMatch { line: 2, column: 2 }));
+    assert_eq!(display.find_next(), None);
+
+    display.search("?", false, true).unwrap();
+    assert_eq!(display.find_next(), Some(SearchMatch { line: 0, column: 0 }));
+}
+
+#[test]
+fn matches_are_highlighted_until_the_search_is_cleared() {
+    let mut display = console();
+    display.print("xab\nbab");
+    let plain = display.framebuffer().to_vec();
+    display.search("ab", false, false).unwrap();
+    // The background of highlighted cells is bright yellow.
+    let background = |display: &DisplayTextManager, column: usize, row: usize| {
+        pixel(display, column * 10 + 9, row * 10 + 9).to_vec()
+    };
+    assert_eq!(background(&display, 1, 0), [85, 255, 255, 0]);
+    assert_eq!(background(&display, 2, 0), [85, 255, 255, 0]);
+    assert_eq!(background(&display, 0, 0), [0, 0, 0, 0]);
+    assert_eq!(background(&display, 0, 1), [0, 0, 0, 0]);
+    assert_eq!(background(&display, 1, 1), [85, 255, 255, 0]);
+
+    // Text printed while searching is highlighted once it matches.
+    display.print("\x1b[3;1Hxa");
+    assert_eq!(background(&display, 1, 2), [0, 0, 0, 0]);
+    display.print("b");
+    assert_eq!(background(&display, 1, 2), [85, 255, 255, 0]);
+
+    display.set_search_highlight((0, 0, 0), (0, 0, 255));
+    assert_eq!(background(&display, 2, 2), [255, 0, 0, 0]);
+
+    display.clear_search();
+    display.print("\x1b[3;1H\x1b[K");
+    assert!(display.framebuffer() == &plain[..]);
+}
+
+#[test]
+fn nothing_is_found_without_a_pattern() {
+    let mut display = console();
+    display.print("ab\ncd\nef\ngh\nij\n");
+    let offset = display.view_offset();
+    assert_eq!(display.find_next(), None);
+    assert_eq!(display.find_previous(), None);
+
+    display.search("", false, false).unwrap();
+    assert_eq!(display.find_next(), None);
+    display.search("ab", false, false).unwrap();
+    display.clear_search();
+    assert_eq!(display.find_previous(), None);
+    assert_eq!(display.view_offset(), offset);
+}
+
+#[test]
+fn long_patterns_are_rejected() {
+    let mut display = console();
+    assert!(display.search(&"x".repeat(MAX_PATTERN_LEN + 1), false, false).is_err());
+    display.search(&"x".repeat(MAX_PATTERN_LEN), false, false).unwrap();
+    assert_eq!(display.find_next(), None);
+}